@@ -0,0 +1,71 @@
+//! Probes whether the compiler rules out an unsatisfied `for<'lt>` bound when
+//! picking a method, which the autoref-based dispatch of `mk_gen!` (see
+//! `InitScoped` in `src/macro_internals.rs`) relies on to fall back to
+//! `GeneratorFn::init()` for the generator functions which cannot be
+//! `.init_scoped()`. Older compilers only reject such a bound afterwards,
+//! in which case `mk_gen!` always uses `.init()`.
+
+use ::std::{
+    env,
+    io::Write,
+    process::{Command, Stdio},
+};
+
+const PROBE: &str = r#"
+    pub trait Scoped<'lt> {}
+    impl<'lt, F : FnOnce(&'lt ())> Scoped<'lt> for F {}
+
+    pub struct Probe<G>(G);
+
+    pub trait ViaScoped { fn pick (self: Self) -> u8; }
+    impl<G> ViaScoped for &'_ &'_ Probe<G> where G : for<'lt> Scoped<'lt> {
+        fn pick (self: Self) -> u8 { 0 }
+    }
+
+    pub trait ViaPlain { fn pick (self: Self) -> u16; }
+    impl<G> ViaPlain for &'_ Probe<G> {
+        fn pick (self: Self) -> u16 { 1 }
+    }
+
+    fn early_bound<'lt> (_: &'lt ()) where () : 'lt {}
+
+    pub fn probe () -> u16 { (&&Probe(early_bound)).pick() }
+"#;
+
+fn main ()
+{
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rustc-check-cfg=cfg(next_gen_scoped_autoref)");
+    if probe_compiles() {
+        println!("cargo:rustc-cfg=next_gen_scoped_autoref");
+    }
+}
+
+fn probe_compiles ()
+  -> bool
+{
+    let rustc = env::var_os("RUSTC").unwrap_or_else(|| "rustc".into());
+    let out_dir = match env::var_os("OUT_DIR") {
+        | Some(it) => it,
+        | None => return false,
+    };
+    let child =
+        Command::new(rustc)
+            .args(&["--edition=2018", "--crate-type=lib", "--emit=metadata"])
+            .args(&["--crate-name=next_gen_probe", "--out-dir"])
+            .arg(out_dir)
+            .arg("-")
+            .stdin(Stdio::piped())
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .spawn()
+    ;
+    let mut child = match child {
+        | Ok(it) => it,
+        | Err(_) => return false,
+    };
+    let written = child.stdin.take().map_or(false, |mut stdin| {
+        stdin.write_all(PROBE.as_bytes()).is_ok()
+    });
+    child.wait().map_or(false, |status| status.success()) && written
+}
@@ -8,3 +8,6 @@ rustup default "$MIRI_NIGHTLY"
 rustup component add miri
 
 cargo miri test
+# The `.init_scoped()` path (branded `YieldSlot`s), on its own for a clearer
+# report should it regress.
+cargo miri test --lib scoped
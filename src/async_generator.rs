@@ -0,0 +1,211 @@
+//! Generators whose body may `.await` actual futures, in between `yield_!`s.
+//!
+//! See [`AsyncGenerator`] for more info.
+
+use_prelude!();
+
+/// The asynchronous counterpart of [`Generator`], polled with the context of
+/// the caller, which lets the body `.await` actual futures.
+///
+/// This is implemented by the [`GeneratorFn`]s with a `()` resume argument,
+/// such as the instances of `#[generator(yield(…), async)]`-annotated
+/// functions, and, with the `"futures-core"` feature enabled, these
+/// implement [`Stream`][`::futures_core::Stream`] as well.
+///
+/// # Example
+///
+/// ```rust
+/// use ::core::{future::Future, task::{Context, Poll}};
+/// use ::next_gen::prelude::*;
+///
+/// async fn fetch (id: u32)
+///   -> String
+/// {
+///     // Some actual `async` operation…
+///     format!("item #{}", id)
+/// }
+///
+/// #[generator(yield(String), async)]
+/// fn items (count: u32)
+/// {
+///     for id in 0 .. count {
+///         let item = fetch(id).await;
+///         yield_!(item);
+///     }
+/// }
+///
+/// /// Resolves to the next state of an `AsyncGenerator`.
+/// struct Resume<'g, G : ?Sized>(Pin<&'g mut G>);
+///
+/// impl<G : ?Sized + AsyncGenerator> Future for Resume<'_, G> {
+///     type Output = GeneratorState<G::Yield, G::Return>;
+///
+///     fn poll (mut self: Pin<&'_ mut Self>, cx: &'_ mut Context<'_>)
+///       -> Poll<Self::Output>
+///     {
+///         self.0.as_mut().poll_resume(cx)
+///     }
+/// }
+///
+/// async fn collect_items ()
+///   -> Vec<String>
+/// {
+///     let mut ret = vec![];
+///     mk_gen!(let mut items = items(3));
+///     while let GeneratorState::Yielded(item) =
+///         Resume(items.as_mut()).await
+///     {
+///         ret.push(item);
+///     }
+///     ret
+/// }
+/// ```
+pub
+trait AsyncGenerator {
+    /// The type of value this generator yields.
+    type Yield;
+
+    /// The type of value this generator returns.
+    type Return;
+
+    /// Resumes the execution of this generator, using the given context to
+    /// register for a wake-up should its body be `.await`ing a future which is
+    /// not ready yet (in which case this returns [`Poll::Pending`]).
+    ///
+    /// Otherwise, this behaves like [`Generator::resume`], and the same
+    /// caveats apply w.r.t. resuming a generator once it has
+    /// [`Returned`][`GeneratorState::Returned`].
+    fn poll_resume (
+        self: Pin<&'_ mut Self>,
+        cx: &'_ mut Context<'_>,
+    ) -> Poll<GeneratorState<Self::Yield, Self::Return>>
+    ;
+}
+
+// # TRANSITIVE IMPLS
+// ## `?Unpin`
+impl<G : ?Sized>
+    AsyncGenerator
+for
+    Pin<&'_ mut G>
+where
+    G : AsyncGenerator,
+{
+    transitive_impl_deferring_to!(|self| (*self).as_mut());
+}
+#[cfg(feature = "alloc")]
+impl<G : ?Sized>
+    AsyncGenerator
+for
+    Pin<::alloc::boxed::Box<G>>
+where
+    G : AsyncGenerator,
+{
+    transitive_impl_deferring_to!(|self| (*self).as_mut());
+}
+
+// ## `Unpin`
+impl<G : ?Sized>
+    AsyncGenerator
+for
+    &'_ mut G
+where
+    G : AsyncGenerator + Unpin,
+{
+    transitive_impl_deferring_to!(|self| Pin::new(&mut **self));
+}
+#[cfg(feature = "alloc")]
+impl<G : ?Sized>
+    AsyncGenerator
+for
+    ::alloc::boxed::Box<G>
+where
+    G : AsyncGenerator + Unpin,
+{
+    transitive_impl_deferring_to!(|self| Pin::new(&mut **self));
+}
+
+// where:
+macro_rules! transitive_impl_deferring_to {(
+    |$self:tt| $expr:expr $(,)?
+) => (
+    type Yield = G::Yield;
+    type Return = G::Return;
+
+    #[inline]
+    fn poll_resume (
+        mut $self: Pin<&'_ mut Self>,
+        cx: &'_ mut Context<'_>,
+    ) -> Poll<GeneratorState<Self::Yield, Self::Return>>
+    {
+        <G as AsyncGenerator>::poll_resume($expr, cx)
+    }
+)} use transitive_impl_deferring_to;
+
+#[cfg(feature = "futures-core")]
+mod stream {
+    use super::*;
+    use ::futures_core::Stream;
+
+    impl<YieldedItem, F : Future>
+        Stream
+    for
+        GeneratorFn<YieldedItem, F, ()>
+    {
+        type Item = YieldedItem;
+
+        #[inline]
+        fn poll_next (
+            self: Pin<&'_ mut Self>,
+            cx: &'_ mut Context<'_>,
+        ) -> Poll<Option<YieldedItem>>
+        {
+            poll_next(self, cx)
+        }
+    }
+
+    impl<Item, R>
+        Stream
+    for
+        dyn '_ + AsyncGenerator<Yield = Item, Return = R>
+    {
+        type Item = Item;
+
+        #[inline]
+        fn poll_next (
+            self: Pin<&'_ mut Self>,
+            cx: &'_ mut Context<'_>,
+        ) -> Poll<Option<Item>>
+        {
+            poll_next(self, cx)
+        }
+    }
+
+    impl<Item, R>
+        Stream
+    for
+        dyn '_ + AsyncGenerator<Yield = Item, Return = R> + Send
+    {
+        type Item = Item;
+
+        #[inline]
+        fn poll_next (
+            self: Pin<&'_ mut Self>,
+            cx: &'_ mut Context<'_>,
+        ) -> Poll<Option<Item>>
+        {
+            poll_next(self, cx)
+        }
+    }
+
+    fn poll_next<G : ?Sized + AsyncGenerator> (
+        generator: Pin<&'_ mut G>,
+        cx: &'_ mut Context<'_>,
+    ) -> Poll<Option<G::Yield>>
+    {
+        generator.poll_resume(cx).map(|state| match state {
+            | GeneratorState::Yielded(x) => Some(x),
+            | GeneratorState::Returned(_) => None,
+        })
+    }
+}
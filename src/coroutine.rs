@@ -1,6 +1,10 @@
+//! Generators **with resume arguments**, seen through the [`Coroutine`]
+//! trait, where the type of the resume argument is an associated type rather
+//! than a generic parameter.
+
 use_prelude!();
 
-/// The trait implemented by [`coroutineFn`]s **with resume arguments**.
+/// The trait implemented by [`GeneratorFn`]s **with resume arguments**.
 ///
 /// # Example
 ///
@@ -9,48 +13,90 @@ use_prelude!();
 ///
 /// fn main ()
 /// {
-///     #[coroutine(i32)]
+///     #[next_gen::coroutine(i32)]
 ///     fn coro_fn (b: bool, mut arg @ yield_: u8)
 ///       -> &'static str
 ///     {
+///         assert!(b);
 ///         assert_eq!(arg, 42);
 ///         arg = yield_!(1);
 ///         assert_eq!(arg, 27);
 ///         return "foo";
 ///     }
 ///
-///     mk_coro!(let mut coro = coro_fn());
+///     mk_coro!(let mut coro = coro_fn(true));
 ///
 ///     let mut next = |arg: u8| coro.as_mut().resume_with(arg);
 ///
 ///     match next(42) {
-///         | GeneratorState::Yield(yielded) => assert_eq!(yielded, 1_i32),
-///         | GeneratorState::Return(_) => unreachable!(),
+///         | GeneratorState::Yielded(yielded) => assert_eq!(yielded, 1_i32),
+///         | GeneratorState::Returned(_) => unreachable!(),
 ///     }
 ///     match next(27) {
-///         | GeneratorState::Yield(_) => unreachable!(),
-///         | GeneratorState::Return(returned) => assert_eq!(returned, "foo"),
+///         | GeneratorState::Yielded(_) => unreachable!(),
+///         | GeneratorState::Returned(returned) => assert_eq!(returned, "foo"),
 ///     }
 /// }
 /// ```
 ///
 /// # `Coroutine` _vs._ `Generator`
 ///
-///   - A `Generator` is a `Coroutine where Resume = ()`,
+///   - A `Generator` is a `Coroutine<ResumeArg = ()>`,
 ///
 ///   - or another way to see it is that a `Coroutine` is a `Generator` which
 ///     has been enhanced with support for resume arguments.
 ///
-/// We thus have the following impls:
+/// But since the resume argument of a [`Generator<ResumeArg>`][`Generator`]
+/// is a generic parameter, code generic over it has to carry that parameter
+/// around everywhere, whereas with `Coroutine`, it is just an associated type:
 ///
 /// ```rust
-/// # const _: &str = stringify! {
-/// impl<C : ?Sized + Coroutine<Resume = ()>> coroutine for C
+/// use ::next_gen::prelude::*;
+///
+/// /// Feeds the coroutine with its yielded values, until it returns.
+/// fn echo<C : ?Sized + Coroutine> (
+///     mut coroutine: Pin<&'_ mut C>,
+///     first: C::ResumeArg,
+/// ) -> C::Return
+/// where
+///     C : Coroutine<Yield = <C as Coroutine>::ResumeArg>,
+/// {
+///     let mut arg = first;
+///     loop {
+///         match coroutine.as_mut().resume_with(arg) {
+///             | GeneratorState::Yielded(it) => arg = it,
+///             | GeneratorState::Returned(ret) => return ret,
+///         }
+///     }
+/// }
+///
+/// #[next_gen::coroutine(u8)]
+/// fn countdown (mut arg @ yield_: u8)
+///   -> &'static str
+/// {
+///     while let Some(next) = arg.checked_sub(1) {
+///         arg = yield_!(next);
+///     }
+///     "Boom!"
+/// }
+///
+/// mk_coro!(let coroutine = countdown());
+/// assert_eq!(echo(coroutine, 3), "Boom!");
+/// ```
+///
+/// We thus have the following impls:
+///
+/// ```text
+/// impl Coroutine for GeneratorFn<Yield, F, ResumeArg>
+/// impl Coroutine for dyn Generator<ResumeArg, Yield = …, Return = …> (+ Send)
 ///
-/// #[cfg(feature = "std")]
-/// impl<C : ?Sized + Coroutine> Coroutine for Pin<Box<C>>
 /// impl<C : ?Sized + Coroutine> Coroutine for Pin<&mut C>
-/// # };
+/// #[cfg(feature = "alloc")]
+/// impl<C : ?Sized + Coroutine> Coroutine for Pin<Box<C>>
+///
+/// impl<C : ?Sized + Coroutine + Unpin> Coroutine for &mut C
+/// #[cfg(feature = "alloc")]
+/// impl<C : ?Sized + Coroutine + Unpin> Coroutine for Box<C>
 /// ```
 pub
 trait Coroutine {
@@ -65,7 +111,7 @@ trait Coroutine {
     /// The type of the stateful resume argument.
     ///
     /// This associated type corresponds to the type of the value this coroutine
-    /// gets when `.resumed_with()`.
+    /// gets when [`.resume_with()`][`Coroutine::resume_with`]-ed.
     type ResumeArg;
 
     /// The type of value this coroutine returns.
@@ -89,24 +135,128 @@ trait Coroutine {
     /// The [`GeneratorState`] enum returned from this function indicates what
     /// state the coroutine is in upon returning.
     ///
-    /// If the [`Yield`][`GeneratorState::Yield`] variant is returned then the
-    /// coroutine has reached a suspension point and a value has been yielded
-    /// out. Coroutines in this state are available for resumption at a later
-    /// point.
+    /// If the [`Yielded`][`GeneratorState::Yielded`] variant is returned then
+    /// the coroutine has reached a suspension point and a value has been
+    /// yielded out. Coroutines in this state are available for resumption at a
+    /// later point.
     ///
-    /// If [`Return`] is returned then the coroutine has completely finished
+    /// If [`Returned`] is returned then the coroutine has completely finished
     /// with the value provided. It is invalid for the coroutine to be resumed
     /// again.
     ///
     /// # Panics
     ///
-    /// This function may panic if it is called after the [`Return`] variant has
-    /// been returned previously. While coroutine literals in the language are
-    /// guaranteed to panic on resuming after [`Return`], this is not guaranteed
-    /// for all implementations of the [`coroutine`] trait.
+    /// This function may panic if it is called after the [`Returned`] variant
+    /// has been returned previously. While coroutine literals in the language
+    /// are guaranteed to panic on resuming after [`Returned`], this is not
+    /// guaranteed for all implementations of the [`Coroutine`] trait.
     ///
-    /// [`Return`]: `GeneratorState::Return`
-    fn resume_with (self: Pin<&'_ mut Self>, _: Self::ResumeArg)
-      -> GeneratorState<Self::Yield, Self::Return>
+    /// [`Returned`]: `GeneratorState::Returned`
+    fn resume_with (
+        self: Pin<&'_ mut Self>,
+        resume_arg: Self::ResumeArg,
+    ) -> GeneratorState<Self::Yield, Self::Return>
     ;
 }
+
+// # BRIDGES WITH `Generator`
+impl<YieldedItem, F : Future, ResumeArg>
+    Coroutine
+for
+    GeneratorFn<YieldedItem, F, ResumeArg>
+{
+    bridge_impl_deferring_to_generator!();
+}
+
+impl<YieldedItem, Return, ResumeArg>
+    Coroutine
+for
+    dyn '_ + Generator<ResumeArg, Yield = YieldedItem, Return = Return>
+{
+    bridge_impl_deferring_to_generator!();
+}
+
+impl<YieldedItem, Return, ResumeArg>
+    Coroutine
+for
+    dyn '_ + Generator<ResumeArg, Yield = YieldedItem, Return = Return> + Send
+{
+    bridge_impl_deferring_to_generator!();
+}
+
+// where:
+macro_rules! bridge_impl_deferring_to_generator {() => (
+    type Yield = YieldedItem;
+    type ResumeArg = ResumeArg;
+    type Return = <Self as Generator<ResumeArg>>::Return;
+
+    #[inline]
+    fn resume_with (
+        self: Pin<&'_ mut Self>,
+        resume_arg: ResumeArg,
+    ) -> GeneratorState<Self::Yield, Self::Return>
+    {
+        <Self as Generator<ResumeArg>>::resume(self, resume_arg)
+    }
+)} use bridge_impl_deferring_to_generator;
+
+// # TRANSITIVE IMPLS
+// ## `?Unpin`
+impl<C : ?Sized>
+    Coroutine
+for
+    Pin<&'_ mut C>
+where
+    C : Coroutine,
+{
+    transitive_impl_deferring_to!(|self| (*self).as_mut());
+}
+#[cfg(feature = "alloc")]
+impl<C : ?Sized>
+    Coroutine
+for
+    Pin<::alloc::boxed::Box<C>>
+where
+    C : Coroutine,
+{
+    transitive_impl_deferring_to!(|self| (*self).as_mut());
+}
+
+// ## `Unpin`
+impl<C : ?Sized>
+    Coroutine
+for
+    &'_ mut C
+where
+    C : Coroutine + Unpin,
+{
+    transitive_impl_deferring_to!(|self| Pin::new(&mut **self));
+}
+#[cfg(feature = "alloc")]
+impl<C : ?Sized>
+    Coroutine
+for
+    ::alloc::boxed::Box<C>
+where
+    C : Coroutine + Unpin,
+{
+    transitive_impl_deferring_to!(|self| Pin::new(&mut **self));
+}
+
+// where:
+macro_rules! transitive_impl_deferring_to {(
+    |$self:tt| $expr:expr $(,)?
+) => (
+    type Yield = C::Yield;
+    type ResumeArg = C::ResumeArg;
+    type Return = C::Return;
+
+    #[inline]
+    fn resume_with (
+        mut $self: Pin<&'_ mut Self>,
+        resume_arg: C::ResumeArg,
+    ) -> GeneratorState<Self::Yield, Self::Return>
+    {
+        <C as Coroutine>::resume_with($expr, resume_arg)
+    }
+)} use transitive_impl_deferring_to;
@@ -1,3 +1,7 @@
+#![cfg_attr(feature = "native-coroutines",
+    feature(coroutines),
+)]
+
 fn main ()
 {
     use ::next_gen::prelude::*;
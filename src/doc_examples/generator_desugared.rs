@@ -10,14 +10,12 @@ fn main ()
             __yield_slot__.__put($value).await
         )}
         let _ = __yield_slot__.__take_initial_arg();
-        {
-            let mut current = count;
-            while let Some(next) = current.checked_sub(1) {
-                yield_!(current);
-                current = next;
-            }
-            value
+        let mut current = count;
+        while let Some(next) = current.checked_sub(1) {
+            yield_!(current);
+            current = next;
         }
+        value
     }
 
     let generator = ::next_gen::generator_fn::GeneratorFn::empty();
@@ -0,0 +1,247 @@
+//! Generators out of plain `async fn`s, which `yield` through a [`Co`] handle.
+//!
+//! See [`from_async`] for more info.
+
+use_prelude!();
+use crate::generator_fn::{internals::YieldSlot, ItemSlot};
+
+/// A handle through which an `async fn` can yield on behalf of the generator
+/// driving it.
+///
+/// These are given to the `async fn`s turned into generators by
+/// [`from_async`], and can, in turn, be lent to helper `async fn`s, so that
+/// yielding logic can be factored out into reusable functions.
+///
+/// Since the generator's body is required to be generic over the `'co`
+/// lifetime of its `Co`, the latter can never escape it (which is what makes
+/// this API safe, contrary to using the raw internals of the
+/// [`#[generator]`][`macro@crate::generator`] macro).
+///
+/// # Example
+///
+/// ```rust
+/// use ::next_gen::{gen_from_async::{Co, from_async}, prelude::*};
+///
+/// async fn yield_pair (co: &'_ Co<'_, u8>, (a, b): (u8, u8))
+/// {
+///     co.yield_(a).await;
+///     co.yield_(b).await;
+/// }
+///
+/// async fn pairs (co: Co<'_, u8>, (count, ): (u8, ))
+/// {
+///     for i in 0 .. count {
+///         yield_pair(&co, (i, 10 * i)).await;
+///     }
+/// }
+///
+/// mk_gen!(let generator = from_async(pairs, (3, )));
+/// assert_eq!(
+///     generator.into_iter().collect::<Vec<_>>(),
+///     [0, 0, 1, 10, 2, 20],
+/// );
+/// ```
+pub
+struct Co<'co, YieldedItem, ResumeArg = ()> {
+    /// Points to the `ItemSlot` of the `GeneratorFn` whose future owns this
+    /// `Co`, and thus, which outlives it.
+    ///
+    /// (Not a `&'co ItemSlot`, since that would require `YieldedItem : 'co`
+    /// and `ResumeArg : 'co`, which `for<'co>` would then turn into
+    /// `'static` requirements.)
+    item_slot: *const ItemSlot<YieldedItem, ResumeArg>,
+    initial_resume_arg: Cell<Option<ResumeArg>>,
+    /// `'co` must not be shrunk, lest the `Co` be able to escape.
+    _invariant: PhantomData<fn(&'co ()) -> &'co ()>,
+}
+
+/// Like a `YieldSlot`, a `Co` can only be sent across threads along with the
+/// `GeneratorFn` whose `ItemSlot` it points to.
+unsafe
+impl<YieldedItem, ResumeArg>
+    Send
+for
+    Co<'_, YieldedItem, ResumeArg>
+where
+    YieldedItem : Send,
+    ResumeArg : Send,
+{}
+
+impl<'co, YieldedItem, ResumeArg> Co<'co, YieldedItem, ResumeArg> {
+    /// Yields `value` out of the generator, and resolves to the argument the
+    /// generator is next [resumed][`Generator::resume`] with.
+    ///
+    /// As with `yield_!`, the returned future must be `.await`ed right away.
+    ///
+    /// A `Co` is not `Sync`, so, for the generator to be `Send` on older
+    /// compilers, bind the future to a local before `.await`ing it (lest the
+    /// `&Co` autoref be deemed held across the `.await`):
+    /// `let yield_ = co.yield_(value); yield_.await`.
+    #[inline]
+    pub
+    fn yield_ (
+        self: &'_ Co<'co, YieldedItem, ResumeArg>,
+        value: YieldedItem,
+    ) -> impl '_ + Future<Output = ResumeArg>
+    {
+        let item_slot = unsafe {
+            // Safety: the `Co` cannot escape the future of the `GeneratorFn`
+            // (the `async fn` being generic over `'co`), which is dropped
+            // before the `ItemSlot` is.
+            &*self.item_slot
+        };
+        item_slot.put(value)
+    }
+
+    /// Takes the argument the generator was started with, _i.e._, the one
+    /// given to its first [`.resume()`][`Generator::resume`] (and which no
+    /// `.yield_()` returns).
+    ///
+    /// Returns `None` if it has already been taken.
+    #[inline]
+    pub
+    fn take_initial_resume_arg (self: &'_ Co<'co, YieldedItem, ResumeArg>)
+      -> Option<ResumeArg>
+    {
+        self.initial_resume_arg.take()
+    }
+}
+
+/// The `async fn(Co<'co, YieldedItem, ResumeArg>, Args) -> Return`s (and
+/// closures) which [`from_async`] can turn into generators.
+///
+/// This is just an [`FnOnce`] whose returned [`Future`] can be named, so as to
+/// be able to require it for all the `'co` lifetimes.
+#[allow(clippy::needless_arbitrary_self_type)]
+pub
+trait CoFnOnce<'co, YieldedItem, ResumeArg, Args> {
+    /// The value returned by the generator, once completed.
+    type Return;
+
+    /// The `Future` returned by the `async fn`.
+    type Future : Future<Output = Self::Return>;
+
+    /// Calls the `async fn`.
+    fn call_once (
+        self: Self,
+        co: Co<'co, YieldedItem, ResumeArg>,
+        args: Args,
+    ) -> Self::Future
+    ;
+}
+
+impl<'co, YieldedItem, ResumeArg, Args, F, Fut>
+    CoFnOnce<'co, YieldedItem, ResumeArg, Args>
+for
+    F
+where
+    F : FnOnce(Co<'co, YieldedItem, ResumeArg>, Args) -> Fut,
+    Fut : Future,
+{
+    type Return = Fut::Output;
+    type Future = Fut;
+
+    #[inline]
+    fn call_once (
+        self: F,
+        co: Co<'co, YieldedItem, ResumeArg>,
+        args: Args,
+    ) -> Fut
+    {
+        self(co, args)
+    }
+}
+
+/// Turns an `async fn(Co<'_, YieldedItem, ResumeArg>, Args) -> Return` (see
+/// [`Co`]) into a generator, without the need for a macro.
+///
+/// This function is to be used as a
+/// [`#[generator]`][`macro@crate::generator`]-annotated one would, taking the
+/// `async fn` and its (packed) `Args` as parameters: with [`mk_gen!`],
+/// [`.call_boxed()`][`crate::generator_fn::CallBoxed::call_boxed`], or
+/// [`GeneratorFn::init`].
+///
+/// # Example
+///
+/// ```rust
+/// use ::next_gen::{gen_from_async::{Co, from_async}, prelude::*};
+///
+/// /// Yields its running total, to which each resume argument is added.
+/// async fn running_total (co: Co<'_, u32, u32>, (mut total, ): (u32, ))
+///   -> u32
+/// {
+///     total += co.take_initial_resume_arg().unwrap();
+///     while total < 10 {
+///         total += co.yield_(total).await;
+///     }
+///     total
+/// }
+///
+/// let mut generator = from_async.call_boxed((running_total, (1, )));
+/// let mut add = |n| generator.as_mut().resume(n);
+/// assert_eq!(add(2), GeneratorState::Yielded(3));
+/// assert_eq!(add(5), GeneratorState::Yielded(8));
+/// assert_eq!(add(4), GeneratorState::Returned(12));
+/// ```
+pub
+fn from_async<'co, YieldedItem, ResumeArg, Args, AsyncFn, Return> (
+    yield_slot: YieldSlot<'co, YieldedItem, ResumeArg>,
+    (async_fn, args): (AsyncFn, Args),
+) -> FromAsync<'co, YieldedItem, ResumeArg, Args, AsyncFn>
+where
+    AsyncFn : for<'any>
+        CoFnOnce<'any, YieldedItem, ResumeArg, Args, Return = Return>
+    ,
+{
+    FromAsync {
+        start: Some((yield_slot, async_fn, args)),
+        future: None,
+    }
+}
+
+/// The [`Future`] returned by [`from_async`].
+///
+/// The `async fn` is only called when first polled, since the initial resume
+/// argument is only available then.
+pub
+struct FromAsync<'co, YieldedItem, ResumeArg, Args, AsyncFn>
+where
+    AsyncFn : CoFnOnce<'co, YieldedItem, ResumeArg, Args>,
+{
+    start: Option<(YieldSlot<'co, YieldedItem, ResumeArg>, AsyncFn, Args)>,
+    future: Option<AsyncFn::Future>,
+}
+
+impl<'co, YieldedItem, ResumeArg, Args, AsyncFn>
+    Future
+for
+    FromAsync<'co, YieldedItem, ResumeArg, Args, AsyncFn>
+where
+    AsyncFn : CoFnOnce<'co, YieldedItem, ResumeArg, Args>,
+{
+    type Output = AsyncFn::Return;
+
+    fn poll (self: Pin<&'_ mut Self>, cx: &'_ mut Context<'_>)
+      -> Poll<AsyncFn::Return>
+    {
+        let this = unsafe {
+            // Safety: `future` is structurally pinned, and `start`, which is
+            // never pinned, is only ever moved out of.
+            self.get_unchecked_mut()
+        };
+        if let Some((yield_slot, async_fn, args)) = this.start.take() {
+            let initial_resume_arg = yield_slot.__take_initial_arg();
+            let co = Co {
+                item_slot: yield_slot.item_slot(),
+                initial_resume_arg: Cell::new(Some(initial_resume_arg)),
+                _invariant: PhantomData,
+            };
+            // The `Co` cannot escape, so there is no need for the runtime
+            // check of the `YieldSlot` anymore.
+            drop(yield_slot);
+            this.future = Some(async_fn.call_once(co, args));
+        }
+        let future = this.future.as_mut().expect("polled after completion");
+        unsafe { Pin::new_unchecked(future) }.poll(cx)
+    }
+}
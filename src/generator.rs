@@ -2,6 +2,14 @@
 
 use_prelude!();
 
+pub use crate::iter::IterPin;
+
+pub mod adaptors;
+
+pub mod merge;
+
+pub mod sources;
+
 /// The trait implemented by [`GeneratorFn`]s.
 ///
 /// Generators, also commonly referred to as coroutines, provide an ergonomic
@@ -163,6 +171,290 @@ trait Generator<ResumeArg = ()> {
     ;
 }
 
+/// Conversion into a [`Generator`], _à la_ [`IntoIterator`], so that APIs can
+/// accept generators, and iterables, uniformly.
+///
+/// It is implemented for:
+///
+///   - every [`Generator`], as is;
+///
+///   - any `IntoIterator`, once wrapped in an [`Iterable`], as a `()`-resumed
+///     generator yielding its items and returning `()`;
+///
+///   - a `()`-resumed generator function (coerced to a `fn` pointer) paired
+///     with its arguments, which get
+///     [`.call_boxed()`][`crate::generator_fn::CallBoxed`].
+///
+/// # A remark regarding the lack of blanket impl and coherence
+///
+/// A blanket impl for all the `IntoIterator`s would overlap with the one for
+/// all the `Generator`s, since pinned `GeneratorFn`s are both (and, as an
+/// `IntoIterator`, they would lose their return value). And impls for some
+/// iterables of the standard library only would be an arbitrary list, which
+/// downstream crates could not extend. Hence iterables having to be wrapped
+/// in an [`Iterable`] (or to be converted with [`sources::from_iter()`], which
+/// returns the number of yielded items).
+///
+/// # Example
+///
+/// ```rust
+/// use ::next_gen::{generator::{IntoGenerator, Iterable}, prelude::*};
+///
+/// /// Resumes `generator` until it returns, collecting what it yields.
+/// fn collect<G : IntoGenerator> (generator: G)
+///   -> (Vec<G::Yield>, G::Return)
+/// {
+///     let generator = generator.into_generator();
+///     stack_pinned!(mut generator);
+///     let mut yielded = vec![];
+///     let ret = generator.drive((), |it| yielded.push(it));
+///     (yielded, ret)
+/// }
+///
+/// #[generator(yield(u8))]
+/// fn countdown (from: u8)
+///   -> &'static str
+/// {
+///     for i in (1 ..= from).rev() {
+///         yield_!(i);
+///     }
+///     "Liftoff!"
+/// }
+///
+/// assert_eq!(collect(countdown.call_boxed((2, ))), (vec![2, 1], "Liftoff!"));
+/// mk_gen!(let generator = countdown(1));
+/// assert_eq!(collect(generator), (vec![1], "Liftoff!"));
+/// let factory: fn(_, _) -> _ = countdown;
+/// assert_eq!(collect((factory, (1, ))), (vec![1], "Liftoff!"));
+/// assert_eq!(collect(Iterable(vec![3, 2, 1])), (vec![3, 2, 1], ()));
+/// assert_eq!(collect(Iterable("ab".chars())), (vec!['a', 'b'], ()));
+/// ```
+#[allow(clippy::needless_arbitrary_self_type)]
+pub
+trait IntoGenerator<ResumeArg = ()> {
+    /// The type of value the generator yields.
+    type Yield;
+
+    /// The type of value the generator returns.
+    type Return;
+
+    /// The generator this is converted into.
+    type IntoGenerator
+        : Generator<ResumeArg, Yield = Self::Yield, Return = Self::Return>
+    ;
+
+    /// Performs the conversion.
+    fn into_generator (self: Self)
+      -> Self::IntoGenerator
+    ;
+}
+
+impl<ResumeArg, G>
+    IntoGenerator<ResumeArg>
+for
+    G
+where
+    G : Generator<ResumeArg>,
+{
+    type Yield = G::Yield;
+
+    type Return = G::Return;
+
+    type IntoGenerator = G;
+
+    #[inline]
+    fn into_generator (self: G)
+      -> G
+    {
+        self
+    }
+}
+
+/// The [`IntoGenerator::IntoGenerator`] of an [`Iterable`].
+pub
+type IterGenerator<I> = adaptors::MapReturn<
+    sources::FromIter<I>,
+    fn(usize),
+>;
+
+/// Wrapper making any [`IntoIterator`] be [`IntoGenerator`] (see the latter
+/// for why this is needed), as a `()`-resumed generator yielding its items and
+/// returning `()`.
+///
+/// # Example
+///
+/// ```rust
+/// use ::next_gen::{generator::Iterable, prelude::*};
+///
+/// let (chars, mut letters) = (Iterable("Hi".chars()), vec![]);
+/// let () = gen_iter!(for c in chars {
+///     letters.push(c);
+/// });
+/// assert_eq!(letters, ['H', 'i']);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub
+struct Iterable<I> (
+    pub I,
+);
+
+impl<I : IntoIterator>
+    IntoGenerator<()>
+for
+    Iterable<I>
+{
+    type Yield = I::Item;
+
+    type Return = ();
+
+    type IntoGenerator = IterGenerator<I::IntoIter>;
+
+    #[inline]
+    fn into_generator (self: Iterable<I>)
+      -> Self::IntoGenerator
+    {
+        adaptors::MapReturn::new(
+            sources::from_iter(self.0),
+            ::core::mem::drop::<usize>,
+        )
+    }
+}
+
+/// A type-erased [`Generator`] trait object: the `dyn` counterpart of
+/// `impl Generator<ResumeArg, Yield = Yield, Return = Return>`.
+///
+/// See [`LocalBoxGenerator`] for its more usable heap-pinned form, and
+/// [`DynGeneratorSend`] for its thread-safe variant.
+pub
+type DynGenerator<'lt, Yield, Return = (), ResumeArg = ()> =
+    dyn 'lt + Generator<ResumeArg, Yield = Yield, Return = Return>
+;
+
+/// Same as [`DynGenerator`], but `Send`.
+pub
+type DynGeneratorSend<'lt, Yield, Return = (), ResumeArg = ()> =
+    dyn 'lt + Generator<ResumeArg, Yield = Yield, Return = Return> + Send
+;
+
+/// A type-erased, heap-pinned and `Send` [`Generator`], for when the type of
+/// a generator needs to be named (_e.g._, in a trait) or be picked at
+/// runtime. See [`LocalBoxGenerator`] for the non-`Send` variant.
+///
+/// Obtained through [`.boxed()`][`GeneratorExt::boxed`], or through
+/// [`.call_boxed()`][`crate::generator_fn::CallBoxed`] (plus an unsizing
+/// coercion) for the `#[generator]`s.
+///
+/// With the default `ResumeArg = ()`, it is an [`Iterator`]; otherwise, it is
+/// [`Unpin`], so [`.feed()`][`GeneratorExt::feed`] or
+/// [`.with_resume_fn()`][`GeneratorExt::with_resume_fn`] can turn it into
+/// one.
+///
+/// # Example
+///
+/// ```rust
+/// use ::next_gen::{generator::{sources, BoxGenerator}, prelude::*};
+///
+/// fn countdown (from: u8, verbose: bool)
+///   -> BoxGenerator<'static, u8, &'static str>
+/// {
+///     let generator = sources::unfold(from, |n: &mut u8, ()| match *n {
+///         | 0 => GeneratorState::Returned("Liftoff!"),
+///         | _ => {
+///             *n -= 1;
+///             GeneratorState::Yielded(*n + 1)
+///         },
+///     });
+///     if verbose {
+///         generator.inspect_yield(|n| println!("{}…", n)).boxed()
+///     } else {
+///         generator.boxed()
+///     }
+/// }
+///
+/// let generator = countdown(3, true);
+/// ::std::thread::spawn(move || {
+///     assert_eq!(generator.collect::<Vec<_>>(), [3, 2, 1]);
+/// }).join().unwrap();
+/// ```
+///
+/// # Borrowed resume arguments
+///
+/// A generic type parameter cannot stand for a higher-ranked type such as
+/// `for<'buf> &'buf [u8]`, so for generators fed borrowed resume arguments,
+/// the `dyn` type has to be spelled out. The generator impls of `Pin<Box<_>>`
+/// and `&mut _` do cover such higher-ranked trait objects:
+///
+/// ```rust
+/// use ::next_gen::{generator::sources, prelude::*};
+///
+/// type LineCounter = Pin<Box<
+///     dyn for<'buf> Generator<&'buf [u8], Yield = usize, Return = ()>
+/// >>;
+///
+/// // Not written inline, lest the closure be inferred for a single `'buf`.
+/// let count = |total: &mut usize, chunk: &[u8]| match chunk {
+///     | [] => GeneratorState::Returned(()),
+///     | _ => {
+///         *total += chunk.iter().filter(|&&b| b == b'\n').count();
+///         GeneratorState::Yielded(*total)
+///     },
+/// };
+/// let mut lines: LineCounter = Box::pin(sources::unfold(0, count));
+/// let chunk = String::from("a\nb\n").into_bytes();
+/// assert_eq!(lines.resume_unpin(&chunk), GeneratorState::Yielded(2));
+/// let chunk = vec![b'c', b'\n'];
+/// assert_eq!(lines.resume_unpin(&chunk), GeneratorState::Yielded(3));
+/// assert_eq!(lines.resume_unpin(&[]), GeneratorState::Returned(()));
+/// ```
+#[cfg(feature = "alloc")]
+pub
+type BoxGenerator<'lt, Yield, Return = (), ResumeArg = ()> =
+    Pin<::alloc::boxed::Box<
+        DynGeneratorSend<'lt, Yield, Return, ResumeArg>
+    >>
+;
+
+/// Same as [`BoxGenerator`], but without the `Send` requirement.
+///
+/// # Example
+///
+/// ```rust
+/// use ::next_gen::{generator::LocalBoxGenerator, prelude::*};
+///
+/// trait Countdown {
+///     fn countdown (self: &'_ Self)
+///       -> LocalBoxGenerator<'_, u8>
+///     ;
+/// }
+///
+/// struct CountdownFrom(u8);
+///
+/// impl Countdown for CountdownFrom {
+///     fn countdown (self: &'_ CountdownFrom)
+///       -> LocalBoxGenerator<'_, u8>
+///     {
+///         #[generator(yield(u8))]
+///         fn countdown (from: &'_ u8)
+///         {
+///             for n in (1 ..= *from).rev() {
+///                 yield_!(n);
+///             }
+///         }
+///
+///         countdown.call_boxed((&self.0, ))
+///     }
+/// }
+///
+/// assert_eq!(CountdownFrom(3).countdown().collect::<Vec<_>>(), [3, 2, 1]);
+/// ```
+#[cfg(feature = "alloc")]
+pub
+type LocalBoxGenerator<'lt, Yield, Return = (), ResumeArg = ()> =
+    Pin<::alloc::boxed::Box<
+        DynGenerator<'lt, Yield, Return, ResumeArg>
+    >>
+;
+
 /// Value obtained when [polling][`Generator::resume`] a [`GeneratorFn`].
 ///
 /// This corresponds to:
@@ -205,6 +497,283 @@ impl<Yield> GeneratorState<Yield, ()> {
     const Complete: Self = Self::Returned(());
 }
 
+impl<Yield, Return> GeneratorState<Yield, Return> {
+    /// Whether this is a [`Yielded`][`GeneratorState::Yielded`] value.
+    #[inline]
+    pub
+    fn is_yielded (self: &'_ GeneratorState<Yield, Return>)
+      -> bool
+    {
+        matches!(*self, GeneratorState::Yielded(_))
+    }
+
+    /// Whether this is a [`Returned`][`GeneratorState::Returned`] value.
+    #[inline]
+    pub
+    fn is_returned (self: &'_ GeneratorState<Yield, Return>)
+      -> bool
+    {
+        matches!(*self, GeneratorState::Returned(_))
+    }
+
+    /// The [`Yielded`][`GeneratorState::Yielded`] value, if any.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ::next_gen::prelude::*;
+    ///
+    /// #[generator(yield(u8))]
+    /// fn countdown (from: u8)
+    /// {
+    ///     for i in (1 ..= from).rev() {
+    ///         yield_!(i);
+    ///     }
+    /// }
+    ///
+    /// mk_gen!(let mut generator = countdown(2));
+    /// let mut next = || generator.as_mut().resume(()).yielded();
+    /// assert_eq!(next(), Some(2));
+    /// assert_eq!(next(), Some(1));
+    /// assert_eq!(next(), None);
+    /// ```
+    #[inline]
+    pub
+    fn yielded (self: GeneratorState<Yield, Return>)
+      -> Option<Yield>
+    {
+        match self {
+            | GeneratorState::Yielded(it) => Some(it),
+            | GeneratorState::Returned(_) => None,
+        }
+    }
+
+    /// The [`Returned`][`GeneratorState::Returned`] value, if any.
+    #[inline]
+    pub
+    fn returned (self: GeneratorState<Yield, Return>)
+      -> Option<Return>
+    {
+        match self {
+            | GeneratorState::Yielded(_) => None,
+            | GeneratorState::Returned(it) => Some(it),
+        }
+    }
+
+    /// Converts from `&GeneratorState<Yield, Return>` to
+    /// `GeneratorState<&Yield, &Return>`.
+    #[inline]
+    pub
+    fn as_ref (self: &'_ GeneratorState<Yield, Return>)
+      -> GeneratorState<&'_ Yield, &'_ Return>
+    {
+        match *self {
+            | GeneratorState::Yielded(ref it) => GeneratorState::Yielded(it),
+            | GeneratorState::Returned(ref it) => GeneratorState::Returned(it),
+        }
+    }
+
+    /// Converts from `&mut GeneratorState<Yield, Return>` to
+    /// `GeneratorState<&mut Yield, &mut Return>`.
+    #[inline]
+    pub
+    fn as_mut (self: &'_ mut GeneratorState<Yield, Return>)
+      -> GeneratorState<&'_ mut Yield, &'_ mut Return>
+    {
+        match *self {
+            | GeneratorState::Yielded(ref mut it) => {
+                GeneratorState::Yielded(it)
+            },
+            | GeneratorState::Returned(ref mut it) => {
+                GeneratorState::Returned(it)
+            },
+        }
+    }
+
+    /// Maps the [`Yielded`][`GeneratorState::Yielded`] value, if any, with
+    /// the given closure, leaving a [`Returned`][`GeneratorState::Returned`]
+    /// one untouched.
+    #[inline]
+    pub
+    fn map_yielded<Yield2> (
+        self: GeneratorState<Yield, Return>,
+        f: impl FnOnce(Yield) -> Yield2,
+    ) -> GeneratorState<Yield2, Return>
+    {
+        match self {
+            | GeneratorState::Yielded(it) => GeneratorState::Yielded(f(it)),
+            | GeneratorState::Returned(it) => GeneratorState::Returned(it),
+        }
+    }
+
+    /// Maps the [`Returned`][`GeneratorState::Returned`] value, if any, with
+    /// the given closure, leaving a [`Yielded`][`GeneratorState::Yielded`]
+    /// one untouched.
+    #[inline]
+    pub
+    fn map_returned<Return2> (
+        self: GeneratorState<Yield, Return>,
+        f: impl FnOnce(Return) -> Return2,
+    ) -> GeneratorState<Yield, Return2>
+    {
+        match self {
+            | GeneratorState::Yielded(it) => GeneratorState::Yielded(it),
+            | GeneratorState::Returned(it) => GeneratorState::Returned(f(it)),
+        }
+    }
+
+    /// `Ok` if [`Yielded`][`GeneratorState::Yielded`], and `Err` if
+    /// [`Returned`][`GeneratorState::Returned`], so that `?` can be used to
+    /// bubble up the return value of a generator.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ::next_gen::prelude::*;
+    ///
+    /// /// Sums the first two yielded values.
+    /// fn sum_two (
+    ///     mut generator: Pin<&mut dyn Generator<Yield = u8, Return = u8>>,
+    /// ) -> Result<u8, u8>
+    /// {
+    ///     let mut next = || generator.as_mut().resume(()).into_result();
+    ///     Ok(next()? + next()?)
+    /// }
+    ///
+    /// #[generator(yield(u8))]
+    /// fn count_to (n: u8)
+    ///   -> u8
+    /// {
+    ///     for i in 1 ..= n {
+    ///         yield_!(i);
+    ///     }
+    ///     n
+    /// }
+    ///
+    /// mk_gen!(let generator = count_to(5));
+    /// assert_eq!(sum_two(generator), Ok(3));
+    /// mk_gen!(let generator = count_to(1));
+    /// assert_eq!(sum_two(generator), Err(1));
+    /// ```
+    #[inline]
+    pub
+    fn into_result (self: GeneratorState<Yield, Return>)
+      -> Result<Yield, Return>
+    {
+        match self {
+            | GeneratorState::Yielded(it) => Ok(it),
+            | GeneratorState::Returned(it) => Err(it),
+        }
+    }
+}
+
+impl<T> GeneratorState<T, T> {
+    /// Extracts the value, be it [`Yielded`][`GeneratorState::Yielded`] or
+    /// [`Returned`][`GeneratorState::Returned`].
+    #[inline]
+    pub
+    fn into_inner (self: GeneratorState<T, T>)
+      -> T
+    {
+        match self {
+            | GeneratorState::Yielded(it) => it,
+            | GeneratorState::Returned(it) => it,
+        }
+    }
+}
+
+impl<Yield, Return>
+    From<Result<Yield, Return>>
+for
+    GeneratorState<Yield, Return>
+{
+    /// The inverse of [`GeneratorState::into_result`].
+    #[inline]
+    fn from (result: Result<Yield, Return>)
+      -> GeneratorState<Yield, Return>
+    {
+        match result {
+            | Ok(it) => GeneratorState::Yielded(it),
+            | Err(it) => GeneratorState::Returned(it),
+        }
+    }
+}
+
+/// A generator which yields `()` is akin to a future: `Yielded(())` is
+/// `Pending`, and `Returned(value)`, `Ready(value)`.
+impl<Return>
+    From<Poll<Return>>
+for
+    GeneratorState<(), Return>
+{
+    #[inline]
+    fn from (poll: Poll<Return>)
+      -> GeneratorState<(), Return>
+    {
+        match poll {
+            | Poll::Pending => GeneratorState::Yielded(()),
+            | Poll::Ready(it) => GeneratorState::Returned(it),
+        }
+    }
+}
+
+/// See the reverse `impl`.
+impl<Return>
+    From<GeneratorState<(), Return>>
+for
+    Poll<Return>
+{
+    #[inline]
+    fn from (state: GeneratorState<(), Return>)
+      -> Poll<Return>
+    {
+        match state {
+            | GeneratorState::Yielded(()) => Poll::Pending,
+            | GeneratorState::Returned(it) => Poll::Ready(it),
+        }
+    }
+}
+
+/// `Yielded` is `Continue`, and `Returned`, `Break`.
+#[cfg(feature = "control-flow")]
+#[allow(clippy::incompatible_msrv)] // opt-in
+impl<Yield, Return>
+    From<GeneratorState<Yield, Return>>
+for
+    ::core::ops::ControlFlow<Return, Yield>
+{
+    #[inline]
+    fn from (state: GeneratorState<Yield, Return>)
+      -> ::core::ops::ControlFlow<Return, Yield>
+    {
+        use ::core::ops::ControlFlow;
+        match state {
+            | GeneratorState::Yielded(it) => ControlFlow::Continue(it),
+            | GeneratorState::Returned(it) => ControlFlow::Break(it),
+        }
+    }
+}
+
+/// See the reverse `impl`.
+#[cfg(feature = "control-flow")]
+#[allow(clippy::incompatible_msrv)] // opt-in
+impl<Yield, Return>
+    From<::core::ops::ControlFlow<Return, Yield>>
+for
+    GeneratorState<Yield, Return>
+{
+    #[inline]
+    fn from (control_flow: ::core::ops::ControlFlow<Return, Yield>)
+      -> GeneratorState<Yield, Return>
+    {
+        use ::core::ops::ControlFlow;
+        match control_flow {
+            | ControlFlow::Continue(it) => GeneratorState::Yielded(it),
+            | ControlFlow::Break(it) => GeneratorState::Returned(it),
+        }
+    }
+}
+
 // # TRANSITIVE IMPLS
 // ## `?Unpin`
 impl<ResumeArg, G : ?Sized>
@@ -263,9 +832,11 @@ macro_rules! transitive_impl_deferring_to {(
     {
         <G as Generator<ResumeArg>>::resume($expr, arg)
     }
+
 )} use transitive_impl_deferring_to;
 
 /// Extension trait with some convenience methods for [`Generator`]s.
+#[allow(clippy::needless_arbitrary_self_type)]
 pub
 trait GeneratorExt<ResumeArg>
 :
@@ -305,6 +876,57 @@ trait GeneratorExt<ResumeArg>
         Pin::new(self).resume(resume_arg)
     }
 
+    /// Type-erases the generator into a [`BoxGenerator`].
+    ///
+    /// See [`.boxed_local()`][`GeneratorExt::boxed_local`] for non-`Send`
+    /// generators.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ::next_gen::{generator::{sources, BoxGenerator}, prelude::*};
+    ///
+    /// let generators: Vec<BoxGenerator<'_, u8, usize>> = vec![
+    ///     sources::from_iter(vec![1, 2]).boxed(),
+    ///     sources::from_iter(3 ..= 4).map_yield(|n| n * 10).boxed(),
+    /// ];
+    /// let yielded = generators.into_iter().flatten().collect::<Vec<_>>();
+    /// assert_eq!(yielded, [1, 2, 30, 40]);
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn boxed<'lt> (self: Self)
+      -> BoxGenerator<'lt, Self::Yield, Self::Return, ResumeArg>
+    where
+        Self : Sized + Send + 'lt,
+    {
+        ::alloc::boxed::Box::pin(self)
+    }
+
+    /// Type-erases the generator into a [`LocalBoxGenerator`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ::next_gen::{generator::{sources, LocalBoxGenerator}, prelude::*};
+    /// use ::std::rc::Rc;
+    ///
+    /// let shared = Rc::new(42);
+    /// let mut generator: LocalBoxGenerator<'_, Rc<i32>, ()> =
+    ///     sources::once(shared.clone()).boxed_local()
+    /// ;
+    /// assert_eq!(generator.next(), Some(shared));
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn boxed_local<'lt> (self: Self)
+      -> LocalBoxGenerator<'lt, Self::Yield, Self::Return, ResumeArg>
+    where
+        Self : Sized + 'lt,
+    {
+        ::alloc::boxed::Box::pin(self)
+    }
+
     /// Convenience method to convert _any_ (boxed) generator into an
     /// iterator.
     ///
@@ -337,7 +959,7 @@ trait GeneratorExt<ResumeArg>
             : Iterator<Item = <Self as Generator<()>>::Yield>
         ,
     {
-        crate::iter::IterPin(self)
+        crate::iter::IterPin::new(self)
     }
 
     /// Convenience method to convert _any_ borring pinned generator into an
@@ -370,7 +992,584 @@ trait GeneratorExt<ResumeArg>
             : Iterator<Item = <Self as Generator<()>>::Yield>
         ,
     {
-        crate::iter::IterPin(self)
+        crate::iter::IterPin::new(self)
+    }
+
+    /// Resumes a generator taking `Result`s as resume arguments (such as a
+    /// `#[generator(…, throws(E))]`-annotated one) with an `Err`or, _i.e._,
+    /// injects `err` into the generator, at its current `yield_!` point.
+    ///
+    /// Basically `g.throw(err)` is sugar for `g.resume(Err(err))`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ::next_gen::prelude::*;
+    ///
+    /// #[derive(Debug, PartialEq)]
+    /// struct Garbage;
+    ///
+    /// /// Sums the numbers it is fed with, until fed some garbage.
+    /// #[generator(yield(u32), resume(u32) as first, throws(Garbage))]
+    /// fn summer ()
+    ///   -> Result<u32, Garbage>
+    /// {
+    ///     let mut sum = first;
+    ///     loop {
+    ///         sum += yield_!(sum)?;
+    ///     }
+    /// }
+    ///
+    /// mk_gen!(let mut generator = summer());
+    /// assert_eq!(generator.as_mut().resume(Ok(1)), GeneratorState::Yielded(1));
+    /// assert_eq!(generator.as_mut().resume(Ok(2)), GeneratorState::Yielded(3));
+    /// assert_eq!(
+    ///     generator.as_mut().throw(Garbage),
+    ///     GeneratorState::Returned(Err(Garbage)),
+    /// );
+    /// ```
+    #[inline]
+    fn throw (
+        self: Pin<&'_ mut Self>,
+        err: ResumeArg::Error,
+    ) -> GeneratorState<Self::Yield, Self::Return>
+    where
+        ResumeArg : Throwable,
+    {
+        self.resume(ResumeArg::thrown(err))
+    }
+
+    /// Maps the yielded values of the generator through `f`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ::next_gen::prelude::*;
+    ///
+    /// #[generator(yield(u8))]
+    /// fn countdown (from: u8)
+    ///   -> &'static str
+    /// {
+    ///     for i in (1 ..= from).rev() {
+    ///         yield_!(i);
+    ///     }
+    ///     "Liftoff!"
+    /// }
+    ///
+    /// mk_gen!(let generator = countdown(2));
+    /// let mut generator = generator.map_yield(|i| i * 10);
+    /// assert_eq!(generator.resume_unpin(()), GeneratorState::Yielded(20));
+    /// assert_eq!(generator.resume_unpin(()), GeneratorState::Yielded(10));
+    /// assert_eq!(
+    ///     generator.resume_unpin(()),
+    ///     GeneratorState::Returned("Liftoff!"),
+    /// );
+    /// ```
+    #[inline]
+    fn map_yield<Yield, F> (
+        self: Self,
+        f: F,
+    ) -> adaptors::MapYield<Self, F>
+    where
+        Self : Sized,
+        F : FnMut(Self::Yield) -> Yield,
+    {
+        adaptors::MapYield::new(self, f)
+    }
+
+    /// Only yields the values of the generator which satisfy `predicate`.
+    ///
+    /// Whenever a value is filtered out, the generator is resumed anew with
+    /// a `.clone()` of the resume argument.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ::next_gen::prelude::*;
+    ///
+    /// #[generator(yield(u8))]
+    /// fn countdown (from: u8)
+    ///   -> &'static str
+    /// {
+    ///     for i in (1 ..= from).rev() {
+    ///         yield_!(i);
+    ///     }
+    ///     "Liftoff!"
+    /// }
+    ///
+    /// mk_gen!(let generator = countdown(4));
+    /// let mut generator = generator.filter_yield(|&i| i % 2 == 1);
+    /// assert_eq!(generator.resume_unpin(()), GeneratorState::Yielded(3));
+    /// assert_eq!(generator.resume_unpin(()), GeneratorState::Yielded(1));
+    /// assert_eq!(
+    ///     generator.resume_unpin(()),
+    ///     GeneratorState::Returned("Liftoff!"),
+    /// );
+    /// ```
+    #[inline]
+    fn filter_yield<P> (
+        self: Self,
+        predicate: P,
+    ) -> adaptors::FilterYield<Self, P>
+    where
+        Self : Sized,
+        P : FnMut(&'_ Self::Yield) -> bool,
+        ResumeArg : Clone,
+    {
+        adaptors::FilterYield::new(self, predicate)
+    }
+
+    /// Maps the return value of the generator through `f`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ::next_gen::prelude::*;
+    ///
+    /// #[generator(yield(u8))]
+    /// fn countdown (from: u8)
+    ///   -> &'static str
+    /// {
+    ///     for i in (1 ..= from).rev() {
+    ///         yield_!(i);
+    ///     }
+    ///     "Liftoff!"
+    /// }
+    ///
+    /// mk_gen!(let generator = countdown(1));
+    /// let mut generator = generator.map_return(str::len);
+    /// assert_eq!(generator.resume_unpin(()), GeneratorState::Yielded(1));
+    /// assert_eq!(generator.resume_unpin(()), GeneratorState::Returned(8));
+    /// ```
+    #[inline]
+    fn map_return<Return, F> (
+        self: Self,
+        f: F,
+    ) -> adaptors::MapReturn<Self, F>
+    where
+        Self : Sized,
+        F : FnOnce(Self::Return) -> Return,
+    {
+        adaptors::MapReturn::new(self, f)
+    }
+
+    /// Calls `f` with a reference to each yielded value, before passing it
+    /// on.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ::next_gen::{generator::GeneratorExt, prelude::*};
+    ///
+    /// #[generator(yield(u8))]
+    /// fn countdown (from: u8)
+    /// {
+    ///     for i in (1 ..= from).rev() {
+    ///         yield_!(i);
+    ///     }
+    /// }
+    ///
+    /// let mut seen = vec![];
+    /// mk_gen!(let generator = countdown(2));
+    /// let mut generator = generator.inspect_yield(|&i| seen.push(i));
+    /// while let GeneratorState::Yielded(_) = generator.resume_unpin(()) {}
+    /// drop(generator);
+    /// assert_eq!(seen, [2, 1]);
+    /// ```
+    #[inline]
+    fn inspect_yield<F> (
+        self: Self,
+        f: F,
+    ) -> adaptors::Inspect<Self, F>
+    where
+        Self : Sized,
+        F : FnMut(&'_ Self::Yield),
+    {
+        adaptors::Inspect::new(self, f)
+    }
+
+    /// Pairs each yielded value with its index.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ::next_gen::{generator::GeneratorExt, prelude::*};
+    ///
+    /// #[generator(yield(char))]
+    /// fn letters ()
+    /// {
+    ///     yield_!('a');
+    ///     yield_!('b');
+    /// }
+    ///
+    /// mk_gen!(let generator = letters());
+    /// let mut generator = generator.enumerate_yield();
+    /// let mut next = || generator.resume_unpin(());
+    /// assert_eq!(next(), GeneratorState::Yielded((0, 'a')));
+    /// assert_eq!(next(), GeneratorState::Yielded((1, 'b')));
+    /// assert_eq!(next(), GeneratorState::Returned(()));
+    /// ```
+    #[inline]
+    fn enumerate_yield (
+        self: Self,
+    ) -> adaptors::Enumerate<Self>
+    where
+        Self : Sized,
+    {
+        adaptors::Enumerate::new(self)
+    }
+
+    /// Yields the values of the generator as long as they satisfy
+    /// `predicate`.
+    ///
+    /// The first value which does not is then returned as an
+    /// [`Either::Right`][`adaptors::Either::Right`], whereas the generator
+    /// returning beforehand results in an
+    /// [`Either::Left`][`adaptors::Either::Left`] of its return value.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ::next_gen::{
+    ///     generator::{adaptors::Either, GeneratorExt},
+    ///     prelude::*,
+    /// };
+    ///
+    /// #[generator(yield(u8))]
+    /// fn countdown (from: u8)
+    ///   -> &'static str
+    /// {
+    ///     for i in (1 ..= from).rev() {
+    ///         yield_!(i);
+    ///     }
+    ///     "Liftoff!"
+    /// }
+    ///
+    /// mk_gen!(let generator = countdown(3));
+    /// let mut generator = generator.take_while_yield(|&i| i > 1);
+    /// assert_eq!(generator.resume_unpin(()), GeneratorState::Yielded(3));
+    /// assert_eq!(generator.resume_unpin(()), GeneratorState::Yielded(2));
+    /// assert_eq!(
+    ///     generator.resume_unpin(()),
+    ///     GeneratorState::Returned(Either::Right(1)),
+    /// );
+    ///
+    /// mk_gen!(let generator = countdown(1));
+    /// let mut generator = generator.take_while_yield(|&i| i > 0);
+    /// assert_eq!(generator.resume_unpin(()), GeneratorState::Yielded(1));
+    /// assert_eq!(
+    ///     generator.resume_unpin(()),
+    ///     GeneratorState::Returned(Either::Left("Liftoff!")),
+    /// );
+    /// ```
+    #[inline]
+    fn take_while_yield<P> (
+        self: Self,
+        predicate: P,
+    ) -> adaptors::TakeWhile<Self, P>
+    where
+        Self : Sized,
+        P : FnMut(&'_ Self::Yield) -> bool,
+    {
+        adaptors::TakeWhile::new(self, predicate)
+    }
+
+    /// Allows to [`.peek()`][`adaptors::Peekable::peek`] at the next state
+    /// of the generator.
+    ///
+    /// See [`Peekable::peek`][`adaptors::Peekable::peek`] for an example.
+    #[inline]
+    fn peekable_yield (
+        self: Self,
+    ) -> adaptors::Peekable<Self, ResumeArg>
+    where
+        Self : Sized,
+    {
+        adaptors::Peekable::new(self)
+    }
+
+    /// Yields the values of the generator, and then, once it returns, those
+    /// of the generator obtained by feeding its return value to
+    /// `make_second` (which may return anything [`IntoGenerator`]).
+    ///
+    /// The resume argument is `.clone()`d when resuming the first generator,
+    /// so as to be able to resume the second one with it.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ::next_gen::{generator::GeneratorExt, prelude::*};
+    ///
+    /// #[generator(yield(u8))]
+    /// fn countdown (from: u8)
+    ///   -> u8
+    /// {
+    ///     for i in (1 ..= from).rev() {
+    ///         yield_!(i);
+    ///     }
+    ///     from
+    /// }
+    ///
+    /// #[generator(yield(u8))]
+    /// fn count_up (to: u8)
+    ///   -> &'static str
+    /// {
+    ///     for i in 1 ..= to {
+    ///         yield_!(i);
+    ///     }
+    ///     "Done"
+    /// }
+    ///
+    /// mk_gen!(let first = countdown(2));
+    /// let mut generator = first.chain_yield(|n| {
+    ///     count_up.call_boxed((n, ))
+    /// });
+    /// for &i in &[2, 1, 1, 2] {
+    ///     assert_eq!(generator.resume_unpin(()), GeneratorState::Yielded(i));
+    /// }
+    /// assert_eq!(
+    ///     generator.resume_unpin(()),
+    ///     GeneratorState::Returned("Done"),
+    /// );
+    /// ```
+    #[inline]
+    fn chain_yield<G, F> (
+        self: Self,
+        make_second: F,
+    ) -> adaptors::Chain<Self, F, G::IntoGenerator>
+    where
+        Self : Sized,
+        F : FnOnce(Self::Return) -> G,
+        G : IntoGenerator<ResumeArg, Yield = Self::Yield>,
+        ResumeArg : Clone,
+    {
+        adaptors::Chain::new(self, make_second)
+    }
+
+    /// Resumes both generators in lockstep, yielding pairs of their values
+    /// (`other` may be anything [`IntoGenerator`]).
+    ///
+    /// As soon as either generator returns, so does the zipped generator,
+    /// with a [`ZipReturn`][`adaptors::ZipReturn`] telling which one did (the
+    /// other one is not resumed any further, and can be gotten back with
+    /// [`.into_inner()`][`adaptors::Zip::into_inner`]).
+    ///
+    /// The resume argument is `.clone()`d to resume each generator.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ::next_gen::{
+    ///     generator::{adaptors::ZipReturn, GeneratorExt},
+    ///     prelude::*,
+    /// };
+    ///
+    /// #[generator(yield(u8))]
+    /// fn countdown (from: u8)
+    ///   -> &'static str
+    /// {
+    ///     for i in (1 ..= from).rev() {
+    ///         yield_!(i);
+    ///     }
+    ///     "Liftoff!"
+    /// }
+    ///
+    /// mk_gen!(let a = countdown(1));
+    /// mk_gen!(let b = countdown(2));
+    /// let mut generator = a.zip_yield(b);
+    /// assert_eq!(generator.resume_unpin(()), GeneratorState::Yielded((1, 2)));
+    /// assert_eq!(
+    ///     generator.resume_unpin(()),
+    ///     GeneratorState::Returned(ZipReturn::First("Liftoff!")),
+    /// );
+    /// // `b` was left as it was.
+    /// let (_, mut b) = generator.into_inner();
+    /// assert_eq!(b.resume_unpin(()), GeneratorState::Yielded(1));
+    /// ```
+    #[inline]
+    fn zip_yield<G> (
+        self: Self,
+        other: G,
+    ) -> adaptors::Zip<Self, G::IntoGenerator>
+    where
+        Self : Sized,
+        G : IntoGenerator<ResumeArg>,
+        ResumeArg : Clone,
+    {
+        adaptors::Zip::new(self, other.into_generator())
+    }
+
+    /// Adapts the resume arguments of the generator: the returned generator
+    /// is resumed with `NewResumeArg`s, which are mapped through `f` before
+    /// being fed to the inner generator.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ::next_gen::prelude::*;
+    ///
+    /// /// Yields the running sum of the numbers it is fed with.
+    /// #[generator(yield(u32), resume(u32) as first)]
+    /// fn summer ()
+    /// {
+    ///     let mut sum = first;
+    ///     loop {
+    ///         sum += yield_!(sum);
+    ///     }
+    /// }
+    ///
+    /// mk_gen!(let generator = summer());
+    /// let mut generator = generator.map_resume(|s: &str| s.len() as u32);
+    /// assert_eq!(generator.resume_unpin("Hello"), GeneratorState::Yielded(5));
+    /// assert_eq!(generator.resume_unpin("!"), GeneratorState::Yielded(6));
+    /// ```
+    #[inline]
+    fn map_resume<NewResumeArg, F> (
+        self: Self,
+        f: F,
+    ) -> adaptors::MapResume<Self, F>
+    where
+        Self : Sized,
+        F : FnMut(NewResumeArg) -> ResumeArg,
+    {
+        adaptors::MapResume::new(self, f)
+    }
+
+    /// Iterates over the values yielded by the generator, resuming it with
+    /// the successive items of `resume_args`.
+    ///
+    /// The iteration ends as soon as either the generator returns, or the
+    /// resume arguments run out. See [`adaptors::Feed`] for more info.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ::next_gen::prelude::*;
+    ///
+    /// /// Yields the running sum of the numbers it is fed with, until fed `0`.
+    /// #[generator(yield(u32), resume(u32) as first)]
+    /// fn summer ()
+    ///   -> u32
+    /// {
+    ///     let mut sum = first;
+    ///     loop {
+    ///         match yield_!(sum) {
+    ///             | 0 => return sum,
+    ///             | n => sum += n,
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// mk_gen!(let generator = summer());
+    /// let mut sums = generator.feed(vec![1, 2, 3, 0, 4]);
+    /// assert_eq!(sums.by_ref().collect::<Vec<_>>(), [1, 3, 6]);
+    /// assert_eq!(sums.into_return(), Some(6));
+    /// ```
+    #[inline]
+    fn feed<ResumeArgs> (
+        self: Self,
+        resume_args: ResumeArgs,
+    ) -> adaptors::Feed<Self, ResumeArgs::IntoIter>
+    where
+        Self : Sized + Unpin,
+        ResumeArgs : IntoIterator<Item = ResumeArg>,
+    {
+        adaptors::Feed::new(self, resume_args.into_iter())
+    }
+
+    /// Iterates over the values yielded by the generator, resuming it with
+    /// the successive values returned by `f`.
+    ///
+    /// The iteration ends once the generator returns. See [`adaptors::Feed`]
+    /// for more info.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ::next_gen::prelude::*;
+    ///
+    /// /// Yields the running sum of the numbers it is fed with.
+    /// #[generator(yield(u32), resume(u32) as first)]
+    /// fn summer ()
+    /// {
+    ///     let mut sum = first;
+    ///     loop {
+    ///         sum += yield_!(sum);
+    ///     }
+    /// }
+    ///
+    /// let mut n = 0;
+    /// mk_gen!(let generator = summer());
+    /// let triangular_numbers = generator.with_resume_fn(|| { n += 1; n });
+    /// assert_eq!(
+    ///     triangular_numbers.take(5).collect::<Vec<_>>(),
+    ///     [1, 3, 6, 10, 15],
+    /// );
+    /// ```
+    #[inline]
+    fn with_resume_fn<F> (
+        self: Self,
+        f: F,
+    ) -> adaptors::Feed<Self, ::core::iter::RepeatWith<F>>
+    where
+        Self : Sized + Unpin,
+        F : FnMut() -> ResumeArg,
+    {
+        self.feed(::core::iter::repeat_with(f))
+    }
+
+    /// Runs the generator to completion, by resuming it with
+    /// `first_resume_arg`, and then with what `f` returns for each yielded
+    /// value.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ::core::cmp::Ordering;
+    /// use ::next_gen::prelude::*;
+    ///
+    /// /// Guesses a number by bisection, being told whether the guesses are
+    /// /// too small or too big.
+    /// #[generator(yield(u8), resume(Ordering))]
+    /// fn guesser ()
+    ///   -> u8
+    /// {
+    ///     let (mut low, mut high) = (u8::MIN, u8::MAX);
+    ///     loop {
+    ///         let guess = low + (high - low) / 2;
+    ///         match yield_!(guess) {
+    ///             | Ordering::Less => low = guess + 1,
+    ///             | Ordering::Greater => high = guess - 1,
+    ///             | Ordering::Equal => return guess,
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// let secret = 42;
+    /// let mut guesses = 0;
+    /// mk_gen!(let generator = guesser());
+    /// let answer = generator.drive(Ordering::Equal, |guess: u8| {
+    ///     guesses += 1;
+    ///     guess.cmp(&secret)
+    /// });
+    /// assert_eq!(answer, secret);
+    /// assert!(guesses <= 8);
+    /// ```
+    fn drive<F> (
+        mut self: Pin<&'_ mut Self>,
+        first_resume_arg: ResumeArg,
+        mut f: F,
+    ) -> Self::Return
+    where
+        F : FnMut(Self::Yield) -> ResumeArg,
+    {
+        let mut resume_arg = first_resume_arg;
+        loop {
+            match self.as_mut().resume(resume_arg) {
+                | GeneratorState::Yielded(it) => resume_arg = f(it),
+                | GeneratorState::Returned(it) => return it,
+            }
+        }
     }
 }
 
@@ -381,3 +1580,31 @@ for
 where
     G : Generator<ResumeArg>,
 {}
+
+/// The resume arguments which can carry an error
+/// [`.throw()`][`GeneratorExt::throw`]n into a generator, _i.e._, `Result`s.
+pub
+trait Throwable {
+    /// The type of the errors which can be thrown.
+    type Error;
+
+    /// Wraps the thrown error into a resume argument.
+    fn thrown (err: Self::Error)
+      -> Self
+    ;
+}
+
+impl<T, E>
+    Throwable
+for
+    Result<T, E>
+{
+    type Error = E;
+
+    #[inline]
+    fn thrown (err: E)
+      -> Result<T, E>
+    {
+        Err(err)
+    }
+}
@@ -0,0 +1,765 @@
+//! [`Generator`] adaptors (and [`Iterator`]s over generators), as obtained
+//! through the [`GeneratorExt`] methods.
+//!
+//! Contrary to converting a generator into an [`Iterator`] and then using the
+//! `Iterator` adaptors, these keep the [`Return`][`Generator::Return`] value
+//! around, as well as the ability to feed resume arguments.
+//!
+//! # Example
+//!
+//! ```rust
+//! use ::next_gen::prelude::*;
+//!
+//! #[generator(yield(u8))]
+//! fn countdown (from: u8)
+//!   -> &'static str
+//! {
+//!     for i in (1 ..= from).rev() {
+//!         yield_!(i);
+//!     }
+//!     "Liftoff!"
+//! }
+//!
+//! mk_gen!(let generator = countdown(3));
+//! let mut generator =
+//!     generator
+//!         .map_yield(|i| i * 10)
+//!         .enumerate_yield()
+//!         .map_return(str::len)
+//! ;
+//! assert_eq!(generator.resume_unpin(()), GeneratorState::Yielded((0, 30)));
+//! assert_eq!(generator.resume_unpin(()), GeneratorState::Yielded((1, 20)));
+//! assert_eq!(generator.resume_unpin(()), GeneratorState::Yielded((2, 10)));
+//! assert_eq!(generator.resume_unpin(()), GeneratorState::Returned(8));
+//! ```
+
+use super::*;
+
+/// The `Return` value of a [`TakeWhile`] generator: either the `Return` value
+/// of the inner generator, or the first yielded item which failed the
+/// predicate.
+#[derive(
+    Debug,
+    Clone, Copy,
+    PartialOrd, Ord,
+    PartialEq, Eq,
+    Hash
+)]
+pub
+enum Either<Left, Right> {
+    /// The inner generator returned.
+    Left(Left),
+
+    /// The inner generator yielded an item which failed the predicate.
+    Right(Right),
+}
+
+/// Splits a pinned adaptor (or combinator) into its (structurally pinned)
+/// inner generators, and the (never pinned) rest of its fields.
+macro_rules! project {(
+    $self:ident => $($pinned:ident),* ; $($unpinned:ident),* $(,)?
+) => (
+    // # Safety
+    //
+    // This is the same as ::pin_project's .project() method:
+    //
+    //   - the `$pinned` fields are considered transitively pinned, and are
+    //     never moved out of (but for `Pin::set`, which drops in place);
+    //
+    //   - no adaptor nor combinator has a `Drop` impl;
+    //
+    //   - no packing.
+    let this = unsafe { Pin::get_unchecked_mut($self) };
+    $(
+        #[allow(unused_mut)]
+        let mut $pinned = unsafe { Pin::new_unchecked(&mut this.$pinned) };
+    )*
+    $(
+        let $unpinned = &mut this.$unpinned;
+    )*
+)} pub(in super) use project;
+
+fn as_pin_mut<T> (this: Pin<&'_ mut Option<T>>)
+  -> Option<Pin<&'_ mut T>>
+{
+    unsafe {
+        // Safety: structural pinning of the `Option`'s payload.
+        Pin::get_unchecked_mut(this)
+            .as_mut()
+            .map(|it| Pin::new_unchecked(it))
+    }
+}
+
+/// Generator adaptor returned by [`GeneratorExt::map_yield`].
+#[derive(Debug, Clone)]
+#[must_use = "generator adaptors are lazy and do nothing unless resumed"]
+pub
+struct MapYield<G, F> {
+    generator: G,
+    f: F,
+}
+
+impl<G, F> MapYield<G, F> {
+    pub(in crate)
+    fn new (generator: G, f: F)
+      -> MapYield<G, F>
+    {
+        MapYield { generator, f }
+    }
+}
+
+impl<ResumeArg, G, F, Yield>
+    Generator<ResumeArg>
+for
+    MapYield<G, F>
+where
+    G : Generator<ResumeArg>,
+    F : FnMut(G::Yield) -> Yield,
+{
+    type Yield = Yield;
+
+    type Return = G::Return;
+
+    fn resume (
+        self: Pin<&'_ mut Self>,
+        resume_arg: ResumeArg,
+    ) -> GeneratorState<Yield, G::Return>
+    {
+        project!(self => generator; f);
+        generator.resume(resume_arg).map_yielded(f)
+    }
+}
+
+/// Generator adaptor returned by [`GeneratorExt::filter_yield`].
+#[derive(Debug, Clone)]
+#[must_use = "generator adaptors are lazy and do nothing unless resumed"]
+pub
+struct FilterYield<G, P> {
+    generator: G,
+    predicate: P,
+}
+
+impl<G, P> FilterYield<G, P> {
+    pub(in crate)
+    fn new (generator: G, predicate: P)
+      -> FilterYield<G, P>
+    {
+        FilterYield { generator, predicate }
+    }
+}
+
+/// The resume argument is `.clone()`d to resume the inner generator anew
+/// whenever one of its items is filtered out.
+impl<ResumeArg, G, P>
+    Generator<ResumeArg>
+for
+    FilterYield<G, P>
+where
+    G : Generator<ResumeArg>,
+    P : FnMut(&'_ G::Yield) -> bool,
+    ResumeArg : Clone,
+{
+    type Yield = G::Yield;
+
+    type Return = G::Return;
+
+    fn resume (
+        self: Pin<&'_ mut Self>,
+        resume_arg: ResumeArg,
+    ) -> GeneratorState<G::Yield, G::Return>
+    {
+        project!(self => generator; predicate);
+        loop {
+            match generator.as_mut().resume(resume_arg.clone()) {
+                | GeneratorState::Yielded(it) if predicate(&it).not() => {},
+                | state => return state,
+            }
+        }
+    }
+}
+
+/// Generator adaptor returned by [`GeneratorExt::map_return`].
+#[derive(Debug, Clone)]
+#[must_use = "generator adaptors are lazy and do nothing unless resumed"]
+pub
+struct MapReturn<G, F> {
+    generator: G,
+    f: Option<F>,
+}
+
+impl<G, F> MapReturn<G, F> {
+    pub(in crate)
+    fn new (generator: G, f: F)
+      -> MapReturn<G, F>
+    {
+        MapReturn { generator, f: Some(f) }
+    }
+}
+
+impl<ResumeArg, G, F, Return>
+    Generator<ResumeArg>
+for
+    MapReturn<G, F>
+where
+    G : Generator<ResumeArg>,
+    F : FnOnce(G::Return) -> Return,
+{
+    type Yield = G::Yield;
+
+    type Return = Return;
+
+    /// Panics if resumed after having returned.
+    fn resume (
+        self: Pin<&'_ mut Self>,
+        resume_arg: ResumeArg,
+    ) -> GeneratorState<G::Yield, Return>
+    {
+        project!(self => generator; f);
+        generator.resume(resume_arg).map_returned(|it| {
+            let f = f.take().expect("`MapReturn` resumed after completion");
+            f(it)
+        })
+    }
+}
+
+/// Generator adaptor returned by [`GeneratorExt::inspect_yield`].
+#[derive(Debug, Clone)]
+#[must_use = "generator adaptors are lazy and do nothing unless resumed"]
+pub
+struct Inspect<G, F> {
+    generator: G,
+    f: F,
+}
+
+impl<G, F> Inspect<G, F> {
+    pub(in crate)
+    fn new (generator: G, f: F)
+      -> Inspect<G, F>
+    {
+        Inspect { generator, f }
+    }
+}
+
+impl<ResumeArg, G, F>
+    Generator<ResumeArg>
+for
+    Inspect<G, F>
+where
+    G : Generator<ResumeArg>,
+    F : FnMut(&'_ G::Yield),
+{
+    type Yield = G::Yield;
+
+    type Return = G::Return;
+
+    fn resume (
+        self: Pin<&'_ mut Self>,
+        resume_arg: ResumeArg,
+    ) -> GeneratorState<G::Yield, G::Return>
+    {
+        project!(self => generator; f);
+        let state = generator.resume(resume_arg);
+        if let GeneratorState::Yielded(ref it) = state {
+            f(it);
+        }
+        state
+    }
+}
+
+/// Generator adaptor returned by [`GeneratorExt::enumerate_yield`].
+#[derive(Debug, Clone)]
+#[must_use = "generator adaptors are lazy and do nothing unless resumed"]
+pub
+struct Enumerate<G> {
+    generator: G,
+    count: usize,
+}
+
+impl<G> Enumerate<G> {
+    pub(in crate)
+    fn new (generator: G)
+      -> Enumerate<G>
+    {
+        Enumerate { generator, count: 0 }
+    }
+}
+
+impl<ResumeArg, G>
+    Generator<ResumeArg>
+for
+    Enumerate<G>
+where
+    G : Generator<ResumeArg>,
+{
+    type Yield = (usize, G::Yield);
+
+    type Return = G::Return;
+
+    fn resume (
+        self: Pin<&'_ mut Self>,
+        resume_arg: ResumeArg,
+    ) -> GeneratorState<(usize, G::Yield), G::Return>
+    {
+        project!(self => generator; count);
+        generator.resume(resume_arg).map_yielded(|it| {
+            let i = *count;
+            *count += 1;
+            (i, it)
+        })
+    }
+}
+
+/// Generator adaptor returned by [`GeneratorExt::take_while_yield`].
+#[derive(Debug, Clone)]
+#[must_use = "generator adaptors are lazy and do nothing unless resumed"]
+pub
+struct TakeWhile<G, P> {
+    generator: G,
+    predicate: P,
+    finished: bool,
+}
+
+impl<G, P> TakeWhile<G, P> {
+    pub(in crate)
+    fn new (generator: G, predicate: P)
+      -> TakeWhile<G, P>
+    {
+        TakeWhile { generator, predicate, finished: false }
+    }
+}
+
+impl<ResumeArg, G, P>
+    Generator<ResumeArg>
+for
+    TakeWhile<G, P>
+where
+    G : Generator<ResumeArg>,
+    P : FnMut(&'_ G::Yield) -> bool,
+{
+    type Yield = G::Yield;
+
+    type Return = Either<G::Return, G::Yield>;
+
+    /// Panics if resumed after having returned.
+    fn resume (
+        self: Pin<&'_ mut Self>,
+        resume_arg: ResumeArg,
+    ) -> GeneratorState<G::Yield, Either<G::Return, G::Yield>>
+    {
+        project!(self => generator; predicate, finished);
+        assert!(finished.not(), "`TakeWhile` resumed after completion");
+        match generator.resume(resume_arg) {
+            | GeneratorState::Yielded(it) if predicate(&it) => {
+                GeneratorState::Yielded(it)
+            },
+            | GeneratorState::Yielded(it) => {
+                *finished = true;
+                GeneratorState::Returned(Either::Right(it))
+            },
+            | GeneratorState::Returned(it) => {
+                *finished = true;
+                GeneratorState::Returned(Either::Left(it))
+            },
+        }
+    }
+}
+
+/// Generator adaptor returned by [`GeneratorExt::peekable_yield`].
+#[must_use = "generator adaptors are lazy and do nothing unless resumed"]
+pub
+struct Peekable<G, ResumeArg>
+where
+    G : Generator<ResumeArg>,
+{
+    generator: G,
+    peeked: Option<GeneratorState<G::Yield, G::Return>>,
+    _resume_arg: PhantomData<fn(ResumeArg)>,
+}
+
+impl<G, ResumeArg> Peekable<G, ResumeArg>
+where
+    G : Generator<ResumeArg>,
+{
+    pub(in crate)
+    fn new (generator: G)
+      -> Peekable<G, ResumeArg>
+    {
+        Peekable { generator, peeked: None, _resume_arg: PhantomData }
+    }
+
+    /// Looks at what the next [`.resume()`][`Generator::resume`] will
+    /// return, without consuming it.
+    ///
+    /// The inner generator is resumed with `resume_arg` if nothing had been
+    /// peeked yet; otherwise `resume_arg` is discarded. Likewise, the resume
+    /// argument given to the `.resume()` following a `.peek()` is discarded.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ::next_gen::{generator::GeneratorExt, prelude::*};
+    ///
+    /// #[generator(yield(u8))]
+    /// fn countdown (from: u8)
+    /// {
+    ///     for i in (1 ..= from).rev() {
+    ///         yield_!(i);
+    ///     }
+    /// }
+    ///
+    /// mk_gen!(let generator = countdown(1));
+    /// let mut generator = generator.peekable_yield();
+    /// let mut generator = Pin::new(&mut generator);
+    /// assert_eq!(generator.as_mut().peek(()), GeneratorState::Yielded(&1));
+    /// assert_eq!(generator.as_mut().peek(()), GeneratorState::Yielded(&1));
+    /// assert_eq!(generator.as_mut().resume(()), GeneratorState::Yielded(1));
+    /// assert_eq!(generator.as_mut().peek(()), GeneratorState::Returned(&()));
+    /// assert_eq!(generator.as_mut().resume(()), GeneratorState::Returned(()));
+    /// ```
+    pub
+    fn peek<'peek> (
+        self: Pin<&'peek mut Peekable<G, ResumeArg>>,
+        resume_arg: ResumeArg,
+    ) -> GeneratorState<&'peek G::Yield, &'peek G::Return>
+    {
+        project!(self => generator; peeked);
+        if peeked.is_none() {
+            *peeked = Some(generator.resume(resume_arg));
+        }
+        match *peeked {
+            | Some(ref state) => state.as_ref(),
+            | None => unreachable!(),
+        }
+    }
+}
+
+impl<ResumeArg, G>
+    Generator<ResumeArg>
+for
+    Peekable<G, ResumeArg>
+where
+    G : Generator<ResumeArg>,
+{
+    type Yield = G::Yield;
+
+    type Return = G::Return;
+
+    fn resume (
+        self: Pin<&'_ mut Self>,
+        resume_arg: ResumeArg,
+    ) -> GeneratorState<G::Yield, G::Return>
+    {
+        project!(self => generator; peeked);
+        match peeked.take() {
+            | Some(state) => state,
+            | None => generator.resume(resume_arg),
+        }
+    }
+}
+
+/// Generator adaptor returned by [`GeneratorExt::chain_yield`].
+#[derive(Debug, Clone)]
+#[must_use = "generator adaptors are lazy and do nothing unless resumed"]
+pub
+struct Chain<G1, F, G2> {
+    first: Option<G1>,
+    second: Option<G2>,
+    make_second: Option<F>,
+}
+
+impl<G1, F, G2> Chain<G1, F, G2> {
+    pub(in crate)
+    fn new (first: G1, make_second: F)
+      -> Chain<G1, F, G2>
+    {
+        Chain {
+            first: Some(first),
+            second: None,
+            make_second: Some(make_second),
+        }
+    }
+}
+
+/// The resume argument is `.clone()`d when resuming the first generator, so
+/// as to be able to resume the second one with it should the first one
+/// return.
+impl<ResumeArg, G1, F, IntoG2, G2>
+    Generator<ResumeArg>
+for
+    Chain<G1, F, G2>
+where
+    G1 : Generator<ResumeArg>,
+    F : FnOnce(G1::Return) -> IntoG2,
+    IntoG2 : IntoGenerator<
+        ResumeArg,
+        Yield = G1::Yield,
+        Return = G2::Return,
+        IntoGenerator = G2,
+    >,
+    G2 : Generator<ResumeArg, Yield = G1::Yield>,
+    ResumeArg : Clone,
+{
+    type Yield = G1::Yield;
+
+    type Return = G2::Return;
+
+    fn resume (
+        self: Pin<&'_ mut Self>,
+        resume_arg: ResumeArg,
+    ) -> GeneratorState<G1::Yield, G2::Return>
+    {
+        project!(self => first, second; make_second);
+        if let Some(generator) = as_pin_mut(first.as_mut()) {
+            match generator.resume(resume_arg.clone()) {
+                | GeneratorState::Yielded(it) => {
+                    return GeneratorState::Yielded(it);
+                },
+                | GeneratorState::Returned(it) => {
+                    first.set(None);
+                    let make_second =
+                        make_second
+                            .take()
+                            .expect("`Chain` resumed after a panic")
+                    ;
+                    second.set(Some(make_second(it).into_generator()));
+                },
+            }
+        }
+        as_pin_mut(second)
+            .expect("`Chain` resumed after a panic")
+            .resume(resume_arg)
+    }
+}
+
+/// Generator adaptor returned by [`GeneratorExt::zip_yield`].
+#[derive(Debug, Clone)]
+#[must_use = "generator adaptors are lazy and do nothing unless resumed"]
+pub
+struct Zip<G1, G2> {
+    first: G1,
+    second: G2,
+    finished: bool,
+}
+
+impl<G1, G2> Zip<G1, G2> {
+    pub(in crate)
+    fn new (first: G1, second: G2)
+      -> Zip<G1, G2>
+    {
+        Zip { first, second, finished: false }
+    }
+
+    /// Gets both generators back (_e.g._, to keep resuming the one which has
+    /// not returned).
+    #[inline]
+    pub
+    fn into_inner (self: Zip<G1, G2>)
+      -> (G1, G2)
+    {
+        (self.first, self.second)
+    }
+}
+
+/// The `Return` value of a [`Zip`] generator, depending on which of the
+/// generators returned (the other one is left as it was).
+#[derive(
+    Debug,
+    Clone, Copy,
+    PartialOrd, Ord,
+    PartialEq, Eq,
+    Hash
+)]
+pub
+enum ZipReturn<Yield1, Return1, Return2> {
+    /// The first generator returned (the second one was not resumed).
+    First(Return1),
+
+    /// The second generator returned, after the first one had yielded an
+    /// item.
+    Second(Yield1, Return2),
+}
+
+/// Both generators are resumed each time (the first one, first), with a
+/// `.clone()` of the resume argument.
+///
+/// As soon as either returns, so does the zipped generator: the other one is
+/// not resumed any further (so that it may as well never return).
+impl<ResumeArg, G1, G2>
+    Generator<ResumeArg>
+for
+    Zip<G1, G2>
+where
+    G1 : Generator<ResumeArg>,
+    G2 : Generator<ResumeArg>,
+    ResumeArg : Clone,
+{
+    type Yield = (G1::Yield, G2::Yield);
+
+    type Return = ZipReturn<G1::Yield, G1::Return, G2::Return>;
+
+    /// Panics if resumed after having returned.
+    fn resume (
+        self: Pin<&'_ mut Self>,
+        resume_arg: ResumeArg,
+    ) -> GeneratorState<Self::Yield, Self::Return>
+    {
+        project!(self => first, second; finished);
+        assert!(finished.not(), "`Zip` resumed after completion");
+        let a = match first.resume(resume_arg.clone()) {
+            | GeneratorState::Yielded(a) => a,
+            | GeneratorState::Returned(it) => {
+                *finished = true;
+                return GeneratorState::Returned(ZipReturn::First(it));
+            },
+        };
+        match second.resume(resume_arg) {
+            | GeneratorState::Yielded(b) => GeneratorState::Yielded((a, b)),
+            | GeneratorState::Returned(it) => {
+                *finished = true;
+                GeneratorState::Returned(ZipReturn::Second(a, it))
+            },
+        }
+    }
+}
+
+/// Generator adaptor returned by [`GeneratorExt::map_resume`].
+#[derive(Debug, Clone)]
+#[must_use = "generator adaptors are lazy and do nothing unless resumed"]
+pub
+struct MapResume<G, F> {
+    generator: G,
+    f: F,
+}
+
+impl<G, F> MapResume<G, F> {
+    pub(in crate)
+    fn new (generator: G, f: F)
+      -> MapResume<G, F>
+    {
+        MapResume { generator, f }
+    }
+}
+
+impl<ResumeArg, G, F, InnerResumeArg>
+    Generator<ResumeArg>
+for
+    MapResume<G, F>
+where
+    F : FnMut(ResumeArg) -> InnerResumeArg,
+    G : Generator<InnerResumeArg>,
+{
+    type Yield = G::Yield;
+
+    type Return = G::Return;
+
+    fn resume (
+        self: Pin<&'_ mut Self>,
+        resume_arg: ResumeArg,
+    ) -> GeneratorState<G::Yield, G::Return>
+    {
+        project!(self => generator; f);
+        generator.resume(f(resume_arg))
+    }
+}
+
+/// [`Iterator`] over the values yielded by a generator resumed with the items
+/// of another iterator, as returned by [`GeneratorExt::feed`] and
+/// [`GeneratorExt::with_resume_fn`].
+///
+/// Like [`IterPin`], it is [fused][`::core::iter::FusedIterator`], and keeps
+/// the return value of the generator, if it gets to return before running
+/// out of resume arguments.
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub
+struct Feed<G, I>
+where
+    I : Iterator,
+    G : Generator<I::Item>,
+{
+    generator: G,
+    resume_args: I,
+    finished: bool,
+    return_value: Option<G::Return>,
+}
+
+impl<G, I> Feed<G, I>
+where
+    I : Iterator,
+    G : Generator<I::Item>,
+{
+    pub(in crate)
+    fn new (generator: G, resume_args: I)
+      -> Feed<G, I>
+    {
+        Feed { generator, resume_args, finished: false, return_value: None }
+    }
+
+    /// The value returned by the generator, once it has returned (and until
+    /// it is taken).
+    #[inline]
+    pub
+    fn take_return (self: &'_ mut Feed<G, I>)
+      -> Option<G::Return>
+    {
+        self.return_value.take()
+    }
+
+    /// Owned version of [`.take_return()`][`Feed::take_return`].
+    #[inline]
+    pub
+    fn into_return (self: Feed<G, I>)
+      -> Option<G::Return>
+    {
+        self.return_value
+    }
+
+    /// Gets the generator back (_e.g._, to keep resuming it once the resume
+    /// arguments have run out).
+    #[inline]
+    pub
+    fn into_inner (self: Feed<G, I>)
+      -> G
+    {
+        self.generator
+    }
+}
+
+impl<G, I> Iterator for Feed<G, I>
+where
+    I : Iterator,
+    G : Generator<I::Item> + Unpin,
+{
+    type Item = G::Yield;
+
+    fn next (self: &'_ mut Feed<G, I>)
+      -> Option<G::Yield>
+    {
+        if self.finished {
+            return None;
+        }
+        let resume_arg = match self.resume_args.next() {
+            | Some(it) => it,
+            | None => {
+                self.finished = true;
+                return None;
+            },
+        };
+        match Pin::new(&mut self.generator).resume(resume_arg) {
+            | GeneratorState::Yielded(it) => Some(it),
+            | GeneratorState::Returned(it) => {
+                self.finished = true;
+                self.return_value = Some(it);
+                None
+            },
+        }
+    }
+}
+
+impl<G, I>
+    ::core::iter::FusedIterator
+for
+    Feed<G, I>
+where
+    I : Iterator,
+    G : Generator<I::Item> + Unpin,
+{}
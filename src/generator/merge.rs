@@ -0,0 +1,520 @@
+//! Combinators merging the values yielded by several (`()`-resumed)
+//! [`Generator`]s into a single generator.
+//!
+//! Once all the sources are exhausted, the merged generator returns their
+//! respective [`Return`][`Generator::Return`] values (but for [`select`],
+//! which returns as soon as any of its sources does).
+//!
+//! The sources may be anything [`IntoGenerator`], although the combinators
+//! over a runtime number of them require the resulting generators to be
+//! [`Unpin`], such as `Pin<Box<dyn Generator<Yield = …, Return = …>>>`s.
+//!
+//! # Example
+//!
+//! ```rust
+//! use ::next_gen::{generator::merge, prelude::*};
+//!
+//! /// Yields the lines of a (sorted) log shard, and returns its name.
+//! #[generator(yield((u32, &'static str)))]
+//! fn shard (name: &'static str, lines: &'static [(u32, &'static str)])
+//!   -> &'static str
+//! {
+//!     for &line in lines {
+//!         yield_!(line);
+//!     }
+//!     name
+//! }
+//!
+//! let shards = vec![
+//!     shard.call_boxed(("a", &[(1, "boot"), (5, "tick"), (9, "halt")][..])),
+//!     shard.call_boxed(("b", &[(2, "connect"), (3, "send")][..])),
+//!     shard.call_boxed(("c", &[][..])),
+//! ];
+//! let mut merged = merge::merge_sorted_by(shards, |&(time, _)| time);
+//! let mut lines = vec![];
+//! let names = loop {
+//!     match merged.resume_unpin(()) {
+//!         | GeneratorState::Yielded((_, line)) => lines.push(line),
+//!         | GeneratorState::Returned(names) => break names,
+//!     }
+//! };
+//! assert_eq!(lines, ["boot", "connect", "send", "tick", "halt"]);
+//! assert_eq!(names, ["a", "b", "c"]);
+//! ```
+
+use super::*;
+use super::adaptors::project;
+#[cfg(feature = "alloc")]
+use ::alloc::{
+    collections::BinaryHeap,
+    vec::Vec,
+};
+#[cfg(feature = "alloc")]
+use ::core::cmp::Reverse;
+
+/// Alternates between the values yielded by `a` and those yielded by `b`,
+/// starting with `a`.
+///
+/// Once either generator returns, the remaining values of the other one are
+/// yielded, and then both `Return` values are returned.
+///
+/// # Example
+///
+/// ```rust
+/// use ::next_gen::{generator::merge, prelude::*};
+///
+/// #[generator(yield(u8))]
+/// fn range (start: u8, end: u8)
+///   -> u8
+/// {
+///     for i in start .. end {
+///         yield_!(i);
+///     }
+///     end - start
+/// }
+///
+/// mk_gen!(let a = range(0, 2));
+/// mk_gen!(let b = range(10, 13));
+/// let mut generator = merge::interleave(a, b);
+/// for &i in &[0, 10, 1, 11, 12] {
+///     assert_eq!(generator.resume_unpin(()), GeneratorState::Yielded(i));
+/// }
+/// assert_eq!(generator.resume_unpin(()), GeneratorState::Returned((2, 3)));
+/// ```
+pub
+fn interleave<A, B> (a: A, b: B)
+  -> Interleave<A::IntoGenerator, B::IntoGenerator>
+where
+    A : IntoGenerator<()>,
+    B : IntoGenerator<(), Yield = A::Yield>,
+{
+    Interleave {
+        a: a.into_generator(),
+        b: b.into_generator(),
+        a_return: None,
+        b_return: None,
+        b_is_next: false,
+    }
+}
+
+/// Generator returned by [`interleave()`].
+#[must_use = "generator combinators are lazy and do nothing unless resumed"]
+pub
+struct Interleave<A, B>
+where
+    A : Generator<()>,
+    B : Generator<(), Yield = A::Yield>,
+{
+    a: A,
+    b: B,
+    a_return: Option<A::Return>,
+    b_return: Option<B::Return>,
+    b_is_next: bool,
+}
+
+impl<A, B>
+    Generator<()>
+for
+    Interleave<A, B>
+where
+    A : Generator<()>,
+    B : Generator<(), Yield = A::Yield>,
+{
+    type Yield = A::Yield;
+
+    type Return = (A::Return, B::Return);
+
+    fn resume (
+        self: Pin<&'_ mut Self>,
+        (): (),
+    ) -> GeneratorState<A::Yield, (A::Return, B::Return)>
+    {
+        project!(self => a, b; a_return, b_return, b_is_next);
+        loop {
+            match (a_return.is_some(), b_return.is_some()) {
+                | (true, true) => return GeneratorState::Returned((
+                    a_return.take().unwrap(),
+                    b_return.take().unwrap(),
+                )),
+                | (true, false) => *b_is_next = true,
+                | (false, true) => *b_is_next = false,
+                | (false, false) => {},
+            }
+            if *b_is_next {
+                match b.as_mut().resume(()) {
+                    | GeneratorState::Yielded(it) => {
+                        *b_is_next = false;
+                        return GeneratorState::Yielded(it);
+                    },
+                    | GeneratorState::Returned(it) => *b_return = Some(it),
+                }
+            } else {
+                match a.as_mut().resume(()) {
+                    | GeneratorState::Yielded(it) => {
+                        *b_is_next = true;
+                        return GeneratorState::Yielded(it);
+                    },
+                    | GeneratorState::Returned(it) => *a_return = Some(it),
+                }
+            }
+        }
+    }
+}
+
+/// Cycles through the `sources`, yielding one value of each in turn, and
+/// skipping the ones which have returned.
+///
+/// Once all of them have returned, their `Return` values are returned (in
+/// the order of the `sources`).
+///
+/// # Example
+///
+/// ```rust
+/// use ::next_gen::{generator::merge, prelude::*};
+///
+/// #[generator(yield(u8))]
+/// fn range (start: u8, end: u8)
+///   -> u8
+/// {
+///     for i in start .. end {
+///         yield_!(i);
+///     }
+///     end - start
+/// }
+///
+/// let mut generator = merge::round_robin(vec![
+///     range.call_boxed((0, 3)),
+///     range.call_boxed((10, 11)),
+///     range.call_boxed((20, 22)),
+/// ]);
+/// for &i in &[0, 10, 20, 1, 21, 2] {
+///     assert_eq!(generator.resume_unpin(()), GeneratorState::Yielded(i));
+/// }
+/// assert_eq!(
+///     generator.resume_unpin(()),
+///     GeneratorState::Returned(vec![3, 1, 2]),
+/// );
+/// ```
+#[cfg(feature = "alloc")]
+pub
+fn round_robin<S> (sources: impl IntoIterator<Item = S>)
+  -> RoundRobin<S::IntoGenerator>
+where
+    S : IntoGenerator<()>,
+    S::IntoGenerator : Unpin,
+{
+    let sources = collect_generators(sources);
+    RoundRobin {
+        returns: sources.iter().map(|_| None).collect(),
+        remaining: sources.len(),
+        sources,
+        next: 0,
+    }
+}
+
+/// Generator returned by [`round_robin()`].
+#[cfg(feature = "alloc")]
+#[must_use = "generator combinators are lazy and do nothing unless resumed"]
+pub
+struct RoundRobin<G>
+where
+    G : Generator<()> + Unpin,
+{
+    sources: Vec<G>,
+    returns: Vec<Option<G::Return>>,
+    remaining: usize,
+    next: usize,
+}
+
+/// No structural pinning whatsoever (`G : Unpin`).
+#[cfg(feature = "alloc")]
+impl<G>
+    Unpin
+for
+    RoundRobin<G>
+where
+    G : Generator<()> + Unpin,
+{}
+
+#[cfg(feature = "alloc")]
+impl<G>
+    Generator<()>
+for
+    RoundRobin<G>
+where
+    G : Generator<()> + Unpin,
+{
+    type Yield = G::Yield;
+
+    type Return = Vec<G::Return>;
+
+    fn resume (
+        self: Pin<&'_ mut Self>,
+        (): (),
+    ) -> GeneratorState<G::Yield, Vec<G::Return>>
+    {
+        let this = Pin::get_mut(self);
+        while this.remaining > 0 {
+            let i = this.next;
+            this.next = (i + 1) % this.sources.len();
+            if this.returns[i].is_some() {
+                continue;
+            }
+            match Pin::new(&mut this.sources[i]).resume(()) {
+                | GeneratorState::Yielded(it) => {
+                    return GeneratorState::Yielded(it);
+                },
+                | GeneratorState::Returned(it) => {
+                    this.returns[i] = Some(it);
+                    this.remaining -= 1;
+                },
+            }
+        }
+        GeneratorState::Returned(take_returns(&mut this.returns))
+    }
+}
+
+/// Merges `sources` yielding values sorted by `key` into a single generator
+/// yielding all these values sorted by `key`, by keeping the next value of
+/// each source in a heap.
+///
+/// Ties are resolved in favor of the earliest source, making the merge
+/// stable. Note that the first resumption resumes every source, so as to
+/// get their first values.
+///
+/// Once all of the `sources` have returned, their `Return` values are
+/// returned (in the order of the `sources`).
+///
+/// See [the module-level example][self] for an example.
+#[cfg(feature = "alloc")]
+pub
+fn merge_sorted_by<S, F, K> (
+    sources: impl IntoIterator<Item = S>,
+    key: F,
+) -> MergeSortedBy<S::IntoGenerator, F, K>
+where
+    S : IntoGenerator<()>,
+    S::IntoGenerator : Unpin,
+    F : FnMut(&'_ S::Yield) -> K,
+    K : Ord,
+{
+    let sources = collect_generators(sources);
+    MergeSortedBy {
+        heads: sources.iter().map(|_| None).collect(),
+        returns: sources.iter().map(|_| None).collect(),
+        heap: BinaryHeap::with_capacity(sources.len()),
+        sources,
+        key,
+        started: false,
+        to_refill: None,
+    }
+}
+
+/// Generator returned by [`merge_sorted_by()`].
+#[cfg(feature = "alloc")]
+#[must_use = "generator combinators are lazy and do nothing unless resumed"]
+pub
+struct MergeSortedBy<G, F, K>
+where
+    G : Generator<()> + Unpin,
+{
+    sources: Vec<G>,
+    /// The next value of each (non-exhausted) source.
+    heads: Vec<Option<G::Yield>>,
+    returns: Vec<Option<G::Return>>,
+    /// The keys of the `heads`, alongside the index of their source.
+    heap: BinaryHeap<Reverse<(K, usize)>>,
+    key: F,
+    started: bool,
+    /// The source whose head was yielded last.
+    to_refill: Option<usize>,
+}
+
+/// No structural pinning whatsoever (`G : Unpin`).
+#[cfg(feature = "alloc")]
+impl<G, F, K>
+    Unpin
+for
+    MergeSortedBy<G, F, K>
+where
+    G : Generator<()> + Unpin,
+{}
+
+#[cfg(feature = "alloc")]
+impl<G, F, K> MergeSortedBy<G, F, K>
+where
+    G : Generator<()> + Unpin,
+    F : FnMut(&'_ G::Yield) -> K,
+    K : Ord,
+{
+    fn refill (self: &'_ mut MergeSortedBy<G, F, K>, i: usize)
+    {
+        match Pin::new(&mut self.sources[i]).resume(()) {
+            | GeneratorState::Yielded(it) => {
+                self.heap.push(Reverse(((self.key)(&it), i)));
+                self.heads[i] = Some(it);
+            },
+            | GeneratorState::Returned(it) => {
+                self.returns[i] = Some(it);
+            },
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<G, F, K>
+    Generator<()>
+for
+    MergeSortedBy<G, F, K>
+where
+    G : Generator<()> + Unpin,
+    F : FnMut(&'_ G::Yield) -> K,
+    K : Ord,
+{
+    type Yield = G::Yield;
+
+    type Return = Vec<G::Return>;
+
+    fn resume (
+        self: Pin<&'_ mut Self>,
+        (): (),
+    ) -> GeneratorState<G::Yield, Vec<G::Return>>
+    {
+        let this = Pin::get_mut(self);
+        if ::core::mem::replace(&mut this.started, true).not() {
+            (0 .. this.sources.len()).for_each(|i| this.refill(i));
+        }
+        if let Some(i) = this.to_refill.take() {
+            this.refill(i);
+        }
+        match this.heap.pop() {
+            | Some(Reverse((_, i))) => {
+                this.to_refill = Some(i);
+                GeneratorState::Yielded(
+                    this.heads[i].take().expect("heap entries have a head")
+                )
+            },
+            | None => {
+                GeneratorState::Returned(take_returns(&mut this.returns))
+            },
+        }
+    }
+}
+
+/// Resumes the `sources` in a round-robin fashion, yielding their values,
+/// until one of them returns: its index and `Return` value are then
+/// returned.
+///
+/// The other sources can then be recovered with
+/// [`.into_sources()`][`Select::into_sources`].
+///
+/// # Panics
+///
+/// If `sources` is empty.
+///
+/// # Example
+///
+/// ```rust
+/// use ::next_gen::{generator::merge, prelude::*};
+///
+/// #[generator(yield(u8))]
+/// fn range (start: u8, end: u8)
+///   -> u8
+/// {
+///     for i in start .. end {
+///         yield_!(i);
+///     }
+///     end - start
+/// }
+///
+/// let mut generator = merge::select(vec![
+///     range.call_boxed((0, 3)),
+///     range.call_boxed((10, 11)),
+/// ]);
+/// for &i in &[0, 10, 1] {
+///     assert_eq!(generator.resume_unpin(()), GeneratorState::Yielded(i));
+/// }
+/// assert_eq!(generator.resume_unpin(()), GeneratorState::Returned((1, 1)));
+/// let mut sources = generator.into_sources();
+/// assert_eq!(sources[0].resume_unpin(()), GeneratorState::Yielded(2));
+/// ```
+#[cfg(feature = "alloc")]
+pub
+fn select<S> (sources: impl IntoIterator<Item = S>)
+  -> Select<S::IntoGenerator>
+where
+    S : IntoGenerator<()>,
+    S::IntoGenerator : Unpin,
+{
+    let sources = collect_generators(sources);
+    assert!(sources.is_empty().not(), "`select()` needs some sources");
+    Select { sources, next: 0 }
+}
+
+/// Generator returned by [`select()`].
+#[cfg(feature = "alloc")]
+#[derive(Debug)]
+#[must_use = "generator combinators are lazy and do nothing unless resumed"]
+pub
+struct Select<G> {
+    sources: Vec<G>,
+    next: usize,
+}
+
+#[cfg(feature = "alloc")]
+impl<G> Select<G> {
+    /// Gets the sources back (_e.g._, to keep resuming the ones which have
+    /// not returned yet).
+    #[inline]
+    pub
+    fn into_sources (self: Select<G>)
+      -> Vec<G>
+    {
+        self.sources
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<G>
+    Generator<()>
+for
+    Select<G>
+where
+    G : Generator<()> + Unpin,
+{
+    type Yield = G::Yield;
+
+    /// The index of the source which returned, and its `Return` value.
+    type Return = (usize, G::Return);
+
+    fn resume (
+        self: Pin<&'_ mut Self>,
+        (): (),
+    ) -> GeneratorState<G::Yield, (usize, G::Return)>
+    {
+        let this = Pin::get_mut(self);
+        let i = this.next;
+        this.next = (i + 1) % this.sources.len();
+        Pin::new(&mut this.sources[i])
+            .resume(())
+            .map_returned(|it| (i, it))
+    }
+}
+
+#[cfg(feature = "alloc")]
+fn collect_generators<S : IntoGenerator<()>> (
+    sources: impl IntoIterator<Item = S>,
+) -> Vec<S::IntoGenerator>
+{
+    sources.into_iter().map(|it| it.into_generator()).collect()
+}
+
+#[cfg(feature = "alloc")]
+fn take_returns<R> (returns: &'_ mut Vec<Option<R>>)
+  -> Vec<R>
+{
+    returns
+        .drain(..)
+        .map(|it| it.expect("all the sources have returned"))
+        .collect()
+}
@@ -0,0 +1,369 @@
+//! Constructors of [`Generator`]s out of closures, iterators or values, for
+//! when a `#[generator]` function would be overkill (_e.g._, test doubles, or
+//! adapters over existing [`Iterator`]s).
+//!
+//! All of these generators are [`Unpin`], so they can be resumed with
+//! [`.resume_unpin()`][`GeneratorExt::resume_unpin`], and be used through the
+//! `&mut G` blanket impl. But for [`from_fn()`] and [`unfold()`], which can
+//! be fed any kind of resume arguments, they are resumed with `()`.
+//!
+//! # Example
+//!
+//! ```rust
+//! use ::next_gen::{generator::sources, prelude::*};
+//!
+//! let mut countdown = sources::unfold(3, |n: &mut u8, ()| match *n {
+//!     | 0 => GeneratorState::Returned("Go!"),
+//!     | _ => {
+//!         *n -= 1;
+//!         GeneratorState::Yielded(*n + 1)
+//!     },
+//! });
+//! assert_eq!(countdown.resume_unpin(()), GeneratorState::Yielded(3));
+//! assert_eq!(countdown.resume_unpin(()), GeneratorState::Yielded(2));
+//! assert_eq!(countdown.resume_unpin(()), GeneratorState::Yielded(1));
+//! assert_eq!(countdown.resume_unpin(()), GeneratorState::Returned("Go!"));
+//! ```
+
+use super::*;
+use ::core::convert::Infallible;
+
+/// A generator whose every [`.resume()`][`Generator::resume`] calls `f`.
+///
+/// # Example
+///
+/// ```rust
+/// use ::next_gen::{generator::sources, prelude::*};
+///
+/// // A test double echoing its resume arguments, until fed `0`.
+/// let mut echo = sources::from_fn(|n: u8| match n {
+///     | 0 => GeneratorState::Returned(()),
+///     | _ => GeneratorState::Yielded(n),
+/// });
+/// assert_eq!(echo.resume_unpin(42), GeneratorState::Yielded(42));
+/// assert_eq!(echo.resume_unpin(27), GeneratorState::Yielded(27));
+/// assert_eq!(echo.resume_unpin(0), GeneratorState::Returned(()));
+/// ```
+#[inline]
+pub
+fn from_fn<ResumeArg, Yield, Return, F> (f: F)
+  -> FromFn<F>
+where
+    F : FnMut(ResumeArg) -> GeneratorState<Yield, Return>,
+{
+    FromFn { f }
+}
+
+/// Generator returned by [`from_fn()`].
+#[derive(Debug, Clone)]
+pub
+struct FromFn<F> {
+    f: F,
+}
+
+/// No structural pinning whatsoever.
+impl<F> Unpin for FromFn<F> {}
+
+impl<ResumeArg, Yield, Return, F>
+    Generator<ResumeArg>
+for
+    FromFn<F>
+where
+    F : FnMut(ResumeArg) -> GeneratorState<Yield, Return>,
+{
+    type Yield = Yield;
+
+    type Return = Return;
+
+    #[inline]
+    fn resume (
+        self: Pin<&'_ mut Self>,
+        resume_arg: ResumeArg,
+    ) -> GeneratorState<Yield, Return>
+    {
+        (Pin::get_mut(self).f)(resume_arg)
+    }
+}
+
+/// A generator whose every [`.resume()`][`Generator::resume`] calls `f` with
+/// a mutable borrow of some `state`.
+///
+/// See [the module-level example][self] for an example.
+#[inline]
+pub
+fn unfold<State, ResumeArg, Yield, Return, F> (state: State, f: F)
+  -> Unfold<State, F>
+where
+    F : FnMut(&'_ mut State, ResumeArg) -> GeneratorState<Yield, Return>,
+{
+    Unfold { state, f }
+}
+
+/// Generator returned by [`unfold()`].
+#[derive(Debug, Clone)]
+pub
+struct Unfold<State, F> {
+    state: State,
+    f: F,
+}
+
+/// No structural pinning whatsoever.
+impl<State, F> Unpin for Unfold<State, F> {}
+
+impl<State, ResumeArg, Yield, Return, F>
+    Generator<ResumeArg>
+for
+    Unfold<State, F>
+where
+    F : FnMut(&'_ mut State, ResumeArg) -> GeneratorState<Yield, Return>,
+{
+    type Yield = Yield;
+
+    type Return = Return;
+
+    #[inline]
+    fn resume (
+        self: Pin<&'_ mut Self>,
+        resume_arg: ResumeArg,
+    ) -> GeneratorState<Yield, Return>
+    {
+        let this = Pin::get_mut(self);
+        (this.f)(&mut this.state, resume_arg)
+    }
+}
+
+/// A generator yielding the items of `iterable`, and then returning how many
+/// of these it has yielded.
+///
+/// # Example
+///
+/// ```rust
+/// use ::next_gen::{generator::sources, prelude::*};
+///
+/// let mut generator = sources::from_iter("Hi".chars());
+/// assert_eq!(generator.resume_unpin(()), GeneratorState::Yielded('H'));
+/// assert_eq!(generator.resume_unpin(()), GeneratorState::Yielded('i'));
+/// assert_eq!(generator.resume_unpin(()), GeneratorState::Returned(2));
+/// ```
+#[inline]
+pub
+fn from_iter<I : IntoIterator> (iterable: I)
+  -> FromIter<I::IntoIter>
+{
+    FromIter { iter: iterable.into_iter(), count: 0 }
+}
+
+/// Generator returned by [`from_iter()`].
+#[derive(Debug, Clone)]
+pub
+struct FromIter<I> {
+    iter: I,
+    count: usize,
+}
+
+/// No structural pinning whatsoever.
+impl<I> Unpin for FromIter<I> {}
+
+impl<I : Iterator>
+    Generator<()>
+for
+    FromIter<I>
+{
+    type Yield = I::Item;
+
+    /// The number of yielded items.
+    type Return = usize;
+
+    #[inline]
+    fn resume (
+        self: Pin<&'_ mut Self>,
+        (): (),
+    ) -> GeneratorState<I::Item, usize>
+    {
+        let this = Pin::get_mut(self);
+        match this.iter.next() {
+            | Some(it) => {
+                this.count += 1;
+                GeneratorState::Yielded(it)
+            },
+            | None => GeneratorState::Returned(this.count),
+        }
+    }
+}
+
+/// A generator yielding `value`, and then returning `()`.
+///
+/// # Example
+///
+/// ```rust
+/// use ::next_gen::{generator::sources, prelude::*};
+///
+/// let mut generator = sources::once(42);
+/// assert_eq!(generator.resume_unpin(()), GeneratorState::Yielded(42));
+/// assert_eq!(generator.resume_unpin(()), GeneratorState::Returned(()));
+/// ```
+#[inline]
+pub
+fn once<Yield> (value: Yield)
+  -> Once<Yield>
+{
+    Once { value: Some(value) }
+}
+
+/// Generator returned by [`once()`].
+#[derive(Debug, Clone)]
+pub
+struct Once<Yield> {
+    value: Option<Yield>,
+}
+
+/// No structural pinning whatsoever.
+impl<Yield> Unpin for Once<Yield> {}
+
+impl<Yield>
+    Generator<()>
+for
+    Once<Yield>
+{
+    type Yield = Yield;
+
+    type Return = ();
+
+    #[inline]
+    fn resume (
+        self: Pin<&'_ mut Self>,
+        (): (),
+    ) -> GeneratorState<Yield, ()>
+    {
+        match Pin::get_mut(self).value.take() {
+            | Some(it) => GeneratorState::Yielded(it),
+            | None => GeneratorState::Returned(()),
+        }
+    }
+}
+
+/// A generator endlessly yielding the values returned by `f`.
+///
+/// # Example
+///
+/// ```rust
+/// use ::next_gen::{generator::sources, prelude::*};
+///
+/// let mut n = 0;
+/// let mut generator = sources::repeat_with(|| { n += 1; n * n });
+/// assert_eq!(generator.resume_unpin(()), GeneratorState::Yielded(1));
+/// assert_eq!(generator.resume_unpin(()), GeneratorState::Yielded(4));
+/// assert_eq!(generator.resume_unpin(()), GeneratorState::Yielded(9));
+/// ```
+#[inline]
+pub
+fn repeat_with<Yield, F> (f: F)
+  -> RepeatWith<F>
+where
+    F : FnMut() -> Yield,
+{
+    RepeatWith { f }
+}
+
+/// Generator returned by [`repeat_with()`].
+#[derive(Debug, Clone)]
+pub
+struct RepeatWith<F> {
+    f: F,
+}
+
+/// No structural pinning whatsoever.
+impl<F> Unpin for RepeatWith<F> {}
+
+impl<Yield, F>
+    Generator<()>
+for
+    RepeatWith<F>
+where
+    F : FnMut() -> Yield,
+{
+    type Yield = Yield;
+
+    /// It never returns.
+    type Return = Infallible;
+
+    #[inline]
+    fn resume (
+        self: Pin<&'_ mut Self>,
+        (): (),
+    ) -> GeneratorState<Yield, Infallible>
+    {
+        GeneratorState::Yielded((Pin::get_mut(self).f)())
+    }
+}
+
+/// A generator which does not yield anything, and directly returns
+/// `Return::default()`.
+///
+/// # Example
+///
+/// ```rust
+/// use ::next_gen::{generator::sources, prelude::*};
+///
+/// let mut generator = sources::empty::<u8, Option<bool>>();
+/// assert_eq!(generator.resume_unpin(()), GeneratorState::Returned(None));
+/// ```
+#[inline]
+pub
+fn empty<Yield, Return : Default> ()
+  -> Empty<Yield, Return>
+{
+    Empty { _phantom: PhantomData }
+}
+
+/// Generator returned by [`empty()`].
+pub
+struct Empty<Yield, Return> {
+    _phantom: PhantomData<fn() -> (Yield, Return)>,
+}
+
+impl<Yield, Return>
+    Clone
+for
+    Empty<Yield, Return>
+{
+    #[inline]
+    fn clone (self: &'_ Empty<Yield, Return>)
+      -> Empty<Yield, Return>
+    {
+        Empty { _phantom: PhantomData }
+    }
+}
+
+impl<Yield, Return>
+    ::core::fmt::Debug
+for
+    Empty<Yield, Return>
+{
+    fn fmt (
+        self: &'_ Empty<Yield, Return>,
+        f: &'_ mut ::core::fmt::Formatter<'_>,
+    ) -> ::core::fmt::Result
+    {
+        f.write_str("Empty")
+    }
+}
+
+impl<Yield, Return : Default>
+    Generator<()>
+for
+    Empty<Yield, Return>
+{
+    type Yield = Yield;
+
+    type Return = Return;
+
+    #[inline]
+    fn resume (
+        self: Pin<&'_ mut Self>,
+        (): (),
+    ) -> GeneratorState<Yield, Return>
+    {
+        GeneratorState::Returned(Return::default())
+    }
+}
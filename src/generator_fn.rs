@@ -17,10 +17,16 @@ mod internals {
     /// which a failguard will detect, causing the program to abort.
     /// You have been warned.
     ///
-    /// For instance, the following code leads to an abort:
+    /// For instance, the following code leads to an abort (whereas it would
+    /// fail to compile with [`GeneratorFn::init_scoped`]):
     ///
     /// ```rust,no_run
-    /// use ::next_gen::{__::__Internals_YieldSlot_DoNotUse__, gen_iter};
+    /// use ::next_gen::{
+    ///     __::__Internals_YieldSlot_DoNotUse__,
+    ///     generator::GeneratorState,
+    ///     generator_fn::GeneratorFn,
+    ///     stack_pinned,
+    /// };
     ///
     /// async fn generator (yield_slot: __Internals_YieldSlot_DoNotUse__<'_, u8, ()>, _: ())
     ///   -> __Internals_YieldSlot_DoNotUse__<'_, u8, ()>
@@ -29,7 +35,15 @@ mod internals {
     /// }
     ///
     /// let dangling_yield_slot = {
-    ///     gen_iter!(for _ in generator() {})
+    ///     // (`mk_gen!` would do the same, since `generator` cannot be
+    ///     // `.init_scoped()`.)
+    ///     let var = GeneratorFn::empty();
+    ///     stack_pinned!(mut var);
+    ///     var.as_mut().init(generator, ());
+    ///     match var.as_mut().resume(()) {
+    ///         | GeneratorState::Returned(yield_slot) => yield_slot,
+    ///         | GeneratorState::Yielded(_) => unreachable!(),
+    ///     }
     ///     // the generator() is dropped and the obtained yield_slot would thus dangle.
     ///     // this is detected by the generator destructor (guaranteed to run
     ///     // thanks to `Pin` guarantees), which then aborts the program to avoid
@@ -40,12 +54,24 @@ mod internals {
     /// ```
     pub
     struct YieldSlot<'yield_slot, YieldedItem, ResumeArg = ()> {
+        /// Points to the `ItemSlot` of the `GeneratorFn` whose future owns
+        /// this `YieldSlot`.
+        ///
+        /// (Not a `&'yield_slot ItemSlot`, since that would require
+        /// `YieldedItem : 'yield_slot` and `ResumeArg : 'yield_slot`, which
+        /// the `for<'brand>` of [`GeneratorFn::init_scoped`] would then turn
+        /// into `'static` requirements.)
+        pub(in super)
+        item_slot: *const ItemSlot<YieldedItem, ResumeArg>,
+
+        /// Invariant, so that the `'yield_slot` brand cannot be shrunk.
         pub(in super)
-        item_slot: &'yield_slot ItemSlot<YieldedItem, ResumeArg>,
+        _brand: PhantomData<fn(&'yield_slot ()) -> &'yield_slot ()>,
     }
 }
 use internals::YieldSlot;
 
+#[allow(clippy::needless_arbitrary_self_type)]
 impl<YieldedItem, ResumeArg>
     Drop
 for
@@ -53,13 +79,83 @@ for
 {
     fn drop (self: &'_ mut Self)
     {
-        self.item_slot.yield_slot_dropped.set(true);
+        self.item_slot().yield_slot_dropped.set(true);
+    }
+}
+
+/// # Safety
+///
+///   - A `YieldSlot` points to the `ItemSlot` of the very `GeneratorFn` whose
+///     future owns it, and only ever accesses it (_i.e._, its `Cell`s) from
+///     within that future, which in turn is only ever polled through a
+///     `Pin<&mut GeneratorFn>`. Every access to the `ItemSlot`, be it from the
+///     body or from the `GeneratorFn` resuming it, thus happens on the one
+///     thread which holds that `&mut` at the time.
+///
+///   - So sending a `YieldSlot` to another thread is only an issue if it can
+///     then be used concurrently with the `GeneratorFn`, _i.e._, if it can
+///     escape the body. Since its lifetime is generic (or higher-ranked, with
+///     [`GeneratorFn::init_scoped`]), it cannot be sent to a non-scoped
+///     thread, and the body cannot be resumed until a scoped one is joined.
+///     Escaping otherwise requires directly (ab)using the `doc(hidden)`
+///     internals of the crate, which the `GeneratorFn` destructor then
+///     detects (see the `YieldSlot` docs).
+///
+///   - The `YieldedItem`s and `ResumeArg`s it transfers go from one thread to
+///     another along with the `GeneratorFn`, hence the `Send` bounds.
+///
+/// It is not `Sync`, though, so the `yield_!` expansion must not keep a
+/// `&YieldSlot` alive across its `.await` (which older compilers would count
+/// as held by the future, making `boxed_send` generators non-`Send`).
+unsafe
+impl<YieldedItem, ResumeArg>
+    Send
+for
+    YieldSlot<'_, YieldedItem, ResumeArg>
+where
+    YieldedItem : Send,
+    ResumeArg : Send,
+{}
+
+/// The reference to the `ItemSlot` held by a `__put()` future, which, like the
+/// `YieldSlot` it comes from, is only ever used within the `GeneratorFn`.
+struct ItemSlotRef<'yield_slot, YieldedItem, ResumeArg> (
+    &'yield_slot ItemSlot<YieldedItem, ResumeArg>,
+);
+
+/// # Safety
+///
+///   - Same as for `YieldSlot`: the `__put()` future holding it borrows from
+///     the `YieldSlot`, and thus cannot escape the body either; polling it
+///     from a scoped thread keeps the `GeneratorFn` from being resumed in the
+///     meantime.
+unsafe
+impl<YieldedItem, ResumeArg>
+    Send
+for
+    ItemSlotRef<'_, YieldedItem, ResumeArg>
+where
+    YieldedItem : Send,
+    ResumeArg : Send,
+{}
+
+#[allow(clippy::needless_arbitrary_self_type)]
+impl<'yield_slot, YieldedItem, ResumeArg>
+    ItemSlotRef<'yield_slot, YieldedItem, ResumeArg>
+{
+    #[inline]
+    fn get (self: &'_ Self)
+      -> &'yield_slot ItemSlot<YieldedItem, ResumeArg>
+    {
+        self.0
     }
 }
 
 enum TransferBox<YieldedItem, ResumeArg> {
     YieldedItem(YieldedItem),
     ResumeArg(ResumeArg),
+    /// Sent by [`GeneratorFn::close`], instead of a `ResumeArg`.
+    Close,
     Empty,
 }
 
@@ -78,44 +174,99 @@ impl<YieldedItem, ResumeArg> TransferBox<YieldedItem, ResumeArg> {
     }
 }
 
-macro_rules! misusage {( $($tt:tt)* ) => (
-    ::core::format_args! {
-        "Misusage of a `YieldSlot`: {}", ::core::format_args!($($tt)*),
+/// Checks a `TransferBox` invariant: always with the `hardened` feature,
+/// reporting the [`Misusage`] as an `Err`or, and only in debug builds
+/// otherwise, where it is a `debug_assert!`ion.
+macro_rules! check {( $cond:expr, $misusage:expr $(,)? ) => (
+    if cfg!(feature = "hardened") {
+        if $cond { Ok(()) } else { Err($misusage) }
+    } else {
+        debug_assert!($cond, "{}", $misusage);
+        Ok::<(), Misusage>(())
     }
 )}
 
+pub(in crate)
 struct ItemSlot<YieldedItem, ResumeArg> {
     transfer_box: Cell<TransferBox<YieldedItem, ResumeArg>>,
     yield_slot_dropped: Cell<bool>,
+    /// Whether the latest yield point can handle a `TransferBox::Close`.
+    closable: Cell<bool>,
+    /// A misusage detected from within the body, for the `GeneratorFn` to
+    /// report once the latter suspends or returns.
+    #[cfg(feature = "hardened")]
+    misusage: Cell<Option<Misusage>>,
 }
 
-impl<'yield_slot, YieldedItem, ResumeArg>
-    YieldSlot<'yield_slot, YieldedItem, ResumeArg>
-{
-    #[inline]
-    fn new (
-        item_slot: &'yield_slot ItemSlot<YieldedItem, ResumeArg>,
-    ) -> YieldSlot<'yield_slot, YieldedItem, ResumeArg>
+impl<YieldedItem, ResumeArg> ItemSlot<YieldedItem, ResumeArg> {
+    /// Reports a misusage detected from within the body: with the `hardened`
+    /// feature, it is stashed for the `GeneratorFn` to report it, the body
+    /// being suspended in the meantime; otherwise, this panics.
+    fn misused<T> (
+        self: &'_ ItemSlot<YieldedItem, ResumeArg>,
+        misusage: Misusage,
+    ) -> Poll<T>
     {
-        Self { item_slot }
+        #[cfg(feature = "hardened")] {
+            self.misusage.set(Some(misusage));
+            Poll::Pending
+        }
+        #[cfg(not(feature = "hardened"))] {
+            panic!("{}", misusage)
+        }
     }
 
-    #[doc(hidden)]
     /// Fills the slot with a value, and returns an `.await`-able to be used as
     /// yield point.
-    pub
-    fn __put (
-        self: &'_ YieldSlot<'yield_slot, YieldedItem, ResumeArg>,
+    pub(in crate)
+    fn put (
+        self: &'_ ItemSlot<YieldedItem, ResumeArg>,
         yielded_item: YieldedItem,
     ) -> impl '_ + Future<Output = ResumeArg>
     {
-        let transfer_box = &self.item_slot.transfer_box;
-        let prev = transfer_box.replace(TransferBox::YieldedItem(yielded_item));
-        debug_assert!(
+        let mut suspension = self.suspend(yielded_item, false);
+        let item_slot = ItemSlotRef(self);
+        poll_fn(move |cx| match Pin::new(&mut suspension).poll(cx) {
+            | Poll::Ready(Ok(resume_arg)) => Poll::Ready(resume_arg),
+            | Poll::Ready(Err(Closed)) => {
+                item_slot.get().misused(Misusage::UnexpectedClose)
+            },
+            | Poll::Pending => Poll::Pending,
+        })
+    }
+
+    /// Same as [`ItemSlot::put`], but for a yield point which
+    /// [`GeneratorFn::close`] may resume with an `Err(Closed)`.
+    pub(in crate)
+    fn put_closable (
+        self: &'_ ItemSlot<YieldedItem, ResumeArg>,
+        yielded_item: YieldedItem,
+    ) -> impl '_ + Future<Output = Result<ResumeArg, Closed>>
+    {
+        self.suspend(yielded_item, true)
+    }
+
+    fn suspend (
+        self: &'_ ItemSlot<YieldedItem, ResumeArg>,
+        yielded_item: YieldedItem,
+        closable: bool,
+    ) -> impl '_ + Unpin + Future<Output = Result<ResumeArg, Closed>>
+    {
+        let prev =
+            self.transfer_box
+                .replace(TransferBox::YieldedItem(yielded_item))
+        ;
+        if let Err(misusage) = check!(
             matches!(prev, TransferBox::Empty),
-            "{}", misusage!("slot was not empty"),
-        );
+            Misusage::SlotNotEmpty,
+        )
+        {
+            let _: Poll<()> = self.misused(misusage);
+        }
+        self.closable.set(closable);
+        let item_slot = ItemSlotRef(self);
         poll_fn(move |_| {
+            let transfer_box = &item_slot.get().transfer_box;
             match TransferBox::take(transfer_box) {
                 | yielded_item @ TransferBox::YieldedItem { .. } => {
                     transfer_box.set(yielded_item);
@@ -123,26 +274,261 @@ impl<'yield_slot, YieldedItem, ResumeArg>
                     // handle.
                     Poll::Pending
                 },
-                | TransferBox::ResumeArg(resume_arg) => Poll::Ready(resume_arg),
-                | TransferBox::Empty => panic!("{}", misusage!("incorrect poll")),
+                | TransferBox::ResumeArg(resume_arg) => Poll::Ready(Ok(resume_arg)),
+                | TransferBox::Close => Poll::Ready(Err(Closed)),
+                | TransferBox::Empty => {
+                    item_slot.get().misused(Misusage::IncorrectPoll)
+                },
             }
         })
     }
+}
+
+impl<'yield_slot, YieldedItem, ResumeArg>
+    YieldSlot<'yield_slot, YieldedItem, ResumeArg>
+{
+    /// # Safety
+    ///
+    /// The `ItemSlot` must outlive the `YieldSlot` (see
+    /// [`GeneratorFn::init_with`]).
+    #[inline]
+    unsafe
+    fn new (
+        item_slot: *const ItemSlot<YieldedItem, ResumeArg>,
+    ) -> YieldSlot<'yield_slot, YieldedItem, ResumeArg>
+    {
+        Self { item_slot, _brand: PhantomData }
+    }
+
+    #[doc(hidden)]
+    /// Fills the slot with a value, and returns an `.await`-able to be used as
+    /// yield point.
+    pub
+    fn __put (
+        self: &'_ YieldSlot<'yield_slot, YieldedItem, ResumeArg>,
+        yielded_item: YieldedItem,
+    ) -> impl '_ + Future<Output = ResumeArg>
+    {
+        self.item_slot().put(yielded_item)
+    }
+
+    #[doc(hidden)]
+    /// The yield point of `closable` generators.
+    pub
+    fn __put_closable (
+        self: &'_ YieldSlot<'yield_slot, YieldedItem, ResumeArg>,
+        yielded_item: YieldedItem,
+    ) -> impl '_ + Future<Output = Result<ResumeArg, Closed>>
+    {
+        self.item_slot().put_closable(yielded_item)
+    }
+
+    /// The `ItemSlot` of the `GeneratorFn` this `YieldSlot` belongs to.
+    pub(in crate)
+    fn item_slot (self: &'_ YieldSlot<'yield_slot, YieldedItem, ResumeArg>)
+      -> &'_ ItemSlot<YieldedItem, ResumeArg>
+    {
+        unsafe {
+            // Safety: the `ItemSlot` outlives the future owning this
+            // `YieldSlot`: either the latter cannot escape that future
+            // (`init_scoped`), or the `GeneratorFn` aborts should it have
+            // escaped (`init`).
+            &*self.item_slot
+        }
+    }
 
     /// Takes the initial `resume_arg` off the slot.
+    ///
+    /// Since this cannot suspend, a misusage here panics, even with the
+    /// `hardened` feature (it is always the first thing a `#[generator]`
+    /// body does, so it cannot fail with the macros of this crate).
     #[doc(hidden)]
     pub
     fn __take_initial_arg (
         self: &'_ YieldSlot<'yield_slot, YieldedItem, ResumeArg>,
     ) -> ResumeArg
     {
-        match TransferBox::take(&self.item_slot.transfer_box) {
+        match TransferBox::take(&self.item_slot().transfer_box) {
             | TransferBox::ResumeArg(resume_arg) => resume_arg,
-            | _ => panic!("{}", misusage!("incorrect `take_initial_arg()`")),
+            | _ => panic!("{}", Misusage::IncorrectTakeInitialArg),
+        }
+    }
+
+    /// Wraps the native coroutine which the body of the generator lowers to
+    /// (with the `native-coroutines` feature) into the [`NativeBody`] of the
+    /// generator, which keeps this `YieldSlot`.
+    #[cfg(feature = "native-coroutines")]
+    #[doc(hidden)]
+    pub
+    fn __native<C> (
+        self: YieldSlot<'yield_slot, YieldedItem, ResumeArg>,
+        coroutine: C,
+    ) -> NativeBody<'yield_slot, YieldedItem, ResumeArg, C::Return, C>
+    where
+        C : ::core::ops::Coroutine<ResumeArg, Yield = YieldedItem>,
+    {
+        NativeBody {
+            yield_slot: self,
+            resume: resume_coroutine::<C, ResumeArg>,
+            coroutine,
         }
     }
 }
 
+#[cfg(feature = "native-coroutines")]
+fn resume_coroutine<C, ResumeArg> (coroutine: Pin<&'_ mut C>, arg: ResumeArg)
+  -> GeneratorState<C::Yield, C::Return>
+where
+    C : ::core::ops::Coroutine<ResumeArg>,
+{
+    coroutine.resume(arg).into()
+}
+
+/// The body of a generator lowered to a native coroutine.
+///
+/// Its `GeneratorFn` resumes the coroutine directly (see [`AsNativeBody`]),
+/// but it is also a proper `Future`, exchanging values through the slot (as
+/// the `yield_!`s of a non-native body do), for when it is wrapped within
+/// another one.
+///
+/// (How to resume the coroutine is stored as a function pointer, so that
+/// none of these impls need bounds, as specialization requires.)
+#[cfg(feature = "native-coroutines")]
+#[doc(hidden)]
+pub
+struct NativeBody<'yield_slot, YieldedItem, ResumeArg, Return, C> {
+    yield_slot: YieldSlot<'yield_slot, YieldedItem, ResumeArg>,
+    resume: fn(Pin<&'_ mut C>, ResumeArg)
+        -> GeneratorState<YieldedItem, Return>
+    ,
+    coroutine: C,
+}
+
+#[cfg(feature = "native-coroutines")]
+impl<YieldedItem, ResumeArg, Return, C>
+    NativeBody<'_, YieldedItem, ResumeArg, Return, C>
+{
+    fn resume (self: Pin<&'_ mut Self>, resume_arg: ResumeArg)
+      -> GeneratorState<YieldedItem, Return>
+    {
+        let this = unsafe {
+            // Safety: `coroutine` is structurally pinned.
+            self.get_unchecked_mut()
+        };
+        let coroutine = unsafe { Pin::new_unchecked(&mut this.coroutine) };
+        (this.resume)(coroutine, resume_arg)
+    }
+
+    /// # Safety
+    ///
+    /// `body` must point to a pinned `Self`, `resume_arg` to an
+    /// `Option<ResumeArg>` (to be taken), and `state` to an
+    /// `Option<GeneratorState<YieldedItem, Return>>` (to be written).
+    unsafe
+    fn resume_erased (body: *mut (), resume_arg: *mut (), state: *mut ())
+    {
+        let body = Pin::new_unchecked(&mut *body.cast::<Self>());
+        let resume_arg =
+            (*resume_arg.cast::<Option<ResumeArg>>())
+                .take()
+                .expect("a resume argument")
+        ;
+        *state.cast::<Option<GeneratorState<YieldedItem, Return>>>() =
+            Some(body.resume(resume_arg))
+        ;
+    }
+}
+
+#[cfg(feature = "native-coroutines")]
+impl<YieldedItem, ResumeArg, Return, C>
+    Future
+for
+    NativeBody<'_, YieldedItem, ResumeArg, Return, C>
+{
+    type Output = Return;
+
+    /// Resumes the coroutine with the resume argument found in the slot, and
+    /// puts what it yields back in it (suspending with `Pending`).
+    fn poll (self: Pin<&'_ mut Self>, _: &'_ mut Context<'_>)
+      -> Poll<Return>
+    {
+        let item_slot = self.yield_slot.item_slot() as *const ItemSlot<_, _>;
+        let item_slot = unsafe {
+            // Safety: the `ItemSlot` outlives the body (see `item_slot()`),
+            // which is not otherwise borrowed while resumed.
+            &*item_slot
+        };
+        let transfer_box = &item_slot.transfer_box;
+        let resume_arg = match TransferBox::take(transfer_box) {
+            | TransferBox::ResumeArg(resume_arg) => resume_arg,
+            | _ => return item_slot.misused(Misusage::IncorrectPoll),
+        };
+        match self.resume(resume_arg) {
+            | GeneratorState::Yielded(yielded_item) => {
+                transfer_box.set(TransferBox::YieldedItem(yielded_item));
+                // propagate a suspension up for `Generator::resume` to handle.
+                Poll::Pending
+            },
+            | GeneratorState::Returned(ret) => Poll::Ready(ret),
+        }
+    }
+}
+
+/// Whether the body of a generator is a [`NativeBody`], which its
+/// `GeneratorFn` may then resume directly. This is decided from the type of
+/// the body (through specialization), not from anything the body could do.
+#[cfg(feature = "native-coroutines")]
+trait AsNativeBody {
+    fn as_native_body (self: Pin<&'_ mut Self>)
+      -> Option<ErasedNativeBody<'_>>
+    ;
+}
+
+#[cfg(feature = "native-coroutines")]
+impl<F> AsNativeBody for F {
+    #[inline]
+    default
+    fn as_native_body (self: Pin<&'_ mut Self>)
+      -> Option<ErasedNativeBody<'_>>
+    {
+        None
+    }
+}
+
+#[cfg(feature = "native-coroutines")]
+impl<YieldedItem, ResumeArg, Return, C>
+    AsNativeBody
+for
+    NativeBody<'_, YieldedItem, ResumeArg, Return, C>
+{
+    #[inline]
+    fn as_native_body (self: Pin<&'_ mut Self>)
+      -> Option<ErasedNativeBody<'_>>
+    {
+        let item_slot = self.yield_slot.item_slot;
+        Some(ErasedNativeBody {
+            item_slot: item_slot.cast(),
+            body: unsafe {
+                // Safety: only used to resume it in place.
+                (self.get_unchecked_mut() as *mut Self).cast()
+            },
+            resume: Self::resume_erased,
+            _borrow: PhantomData,
+        })
+    }
+}
+
+/// A (pinned) [`NativeBody`] whose `YieldedItem` and `ResumeArg` types have
+/// been erased: these are known to be the ones of a `GeneratorFn` once
+/// `item_slot` is checked to be the address of its own `ItemSlot`.
+#[cfg(feature = "native-coroutines")]
+struct ErasedNativeBody<'body> {
+    item_slot: *const (),
+    body: *mut (),
+    resume: unsafe fn(*mut (), *mut (), *mut ()),
+    _borrow: PhantomData<&'body mut ()>,
+}
+
 /// An _instance_ of a [`#[generator]`][gen]-tagged function.
 ///
 /// [gen]: `macro@crate::generator`
@@ -269,11 +655,23 @@ struct GeneratorFn<YieldedItem, F : Future, ResumeArg> {
 
     future: Option<F>,
 
+    state: GeneratorFnState,
+
+    /// Whether the last [`AsyncGenerator::poll_resume`] was suspended on
+    /// some actual `.await` (rather than a `yield_!`), in which case no resume
+    /// argument ought to be fed when polling again.
+    suspended_on_await: bool,
+
+    /// Where the `Iterator` impls stash the return value they cannot yield
+    /// (see [`GeneratorFn::take_return`]).
+    return_value: Option<F::Output>,
+
     /// Once a `GeneratorFn` has been pinned, its Drop (glue) must be run
     /// before being deallocated!
     _pin_sensitive: PhantomPinned,
 }
 
+#[allow(clippy::needless_arbitrary_self_type)]
 impl<YieldedItem, F : Future, ResumeArg>
     Drop
 for
@@ -281,13 +679,17 @@ for
 {
     fn drop (self: &'_ mut Self)
     {
-        let Self { ref mut future, ref mut item_slot, .. } = *self;
+        let Self { ref mut future, ref mut item_slot, state, .. } = *self;
         ::unwind_safe::with_state(())
             .try_eval(move |&mut ()| {
                 // drop the future *in place*
                 *future = None;
             })
-            .finally(move |()| if item_slot.yield_slot_dropped.get_mut().not() {
+            .finally(move |()| if
+                // No `YieldSlot` is ever created for an `Empty` generator.
+                state != GeneratorFnState::Empty
+                && item_slot.yield_slot_dropped.get_mut().not()
+            {
                 macros::abort_with_msg!("\
                     `::next_gen` fatal runtime error: \
                     a `YieldSlot` was about to dangle!\
@@ -304,9 +706,415 @@ for
     }
 }
 
+/// A `&GeneratorFn` only gives access to its (`Copy`)
+/// [`.state()`][`GeneratorFn::state`], the slot and body of the generator
+/// (_e.g._, its `Cell`s) being only ever accessed through `Pin<&mut Self>`.
+unsafe
+impl<YieldedItem, F : Future, ResumeArg>
+    Sync
+for
+    GeneratorFn<YieldedItem, F, ResumeArg>
+{}
+
+/// The lifecycle of a [`GeneratorFn`], as queried by
+/// [`.state()`][`GeneratorFn::state`].
+///
+/// ```text
+/// Empty --init--> Unstarted --resume--> Suspended --resume--> Completed
+///                     |                  ^     |
+///                     |                  +-----+
+///                     +----- (panic while resumed) -----> Poisoned
+/// ```
+#[derive(
+    Debug,
+    Clone, Copy,
+    PartialEq, Eq,
+    Hash,
+)]
+pub
+enum GeneratorFnState {
+    /// [`GeneratorFn::empty`] has been called, but not yet
+    /// [`.init()`][`GeneratorFn::init`].
+    Empty,
+
+    /// The generator has been [`.init()`][`GeneratorFn::init`]-ed, but not yet
+    /// resumed.
+    Unstarted,
+
+    /// The generator has yielded, or, for an [`AsyncGenerator`], is
+    /// `.await`ing some future, and can thus be resumed again.
+    Suspended,
+
+    /// The generator has returned: its body has already been dropped, and it
+    /// cannot be resumed anymore.
+    Completed,
+
+    /// The body of the generator panicked while being resumed, which has left
+    /// it in an unusable state.
+    Poisoned,
+}
+
+/// The error returned by [`GeneratorFn::try_resume`] (and
+/// [`GeneratorFn::try_poll_resume`]) when the generator cannot be resumed.
+///
+/// Its [`Display`][`::core::fmt::Display`] is the message with which
+/// [`Generator::resume`] panics in these situations.
+#[derive(
+    Debug,
+    Clone, Copy,
+    PartialEq, Eq,
+    Hash,
+)]
+pub
+enum ResumeError {
+    /// The generator has not been [`.init()`][`GeneratorFn::init`]-ed.
+    Empty,
+
+    /// The generator has already [returned][`GeneratorState::Returned`].
+    Completed,
+
+    /// The generator previously panicked while being resumed.
+    Poisoned,
+
+    /// The internals of the generator were misused (only reported as such
+    /// with the `hardened` feature, a panic occurring otherwise).
+    ///
+    /// The generator is then [`Poisoned`][`GeneratorFnState::Poisoned`].
+    Misusage(Misusage),
+}
+
+#[allow(clippy::needless_arbitrary_self_type)]
+impl ::core::fmt::Display for ResumeError {
+    fn fmt (self: &'_ Self, f: &'_ mut ::core::fmt::Formatter<'_>)
+      -> ::core::fmt::Result
+    {
+        f.write_str(match *self {
+            | ResumeError::Empty => {
+                "You must init a GeneratorFn before using it!"
+            },
+            | ResumeError::Completed => {
+                "`GeneratorFn` resumed after completion"
+            },
+            | ResumeError::Poisoned => {
+                "`GeneratorFn` resumed after panicking"
+            },
+            | ResumeError::Misusage(ref misusage) => {
+                return ::core::fmt::Display::fmt(misusage, f);
+            },
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+impl ::std::error::Error for ResumeError {}
+
+impl From<Misusage> for ResumeError {
+    #[inline]
+    fn from (misusage: Misusage)
+      -> ResumeError
+    {
+        ResumeError::Misusage(misusage)
+    }
+}
+
+/// An inconsistency of the slot through which a [`GeneratorFn`] and its body
+/// exchange the yielded items and resume arguments, which can only happen if
+/// the internals of the crate are (ab)used directly.
+///
+/// By default, these are `debug_assert!`ed, or lead to a panic. With the
+/// `hardened` feature, they are always checked, and reported through
+/// [`GeneratorFn::try_resume`] (as a [`ResumeError::Misusage`]), poisoning
+/// the generator.
+#[derive(
+    Debug,
+    Clone, Copy,
+    PartialEq, Eq,
+    Hash,
+)]
+pub
+enum Misusage {
+    /// An item was yielded while the slot was not empty, _e.g._, because a
+    /// previous `__put()` was not `.await`ed.
+    SlotNotEmpty,
+
+    /// A resume was started while the slot was not empty.
+    NotEmptyOnResume,
+
+    /// A yield point was polled while the slot held no resume argument.
+    IncorrectPoll,
+
+    /// The initial resume argument was not in the slot.
+    IncorrectTakeInitialArg,
+
+    /// The body suspended without having yielded an item.
+    MissingYieldedItem,
+
+    /// The body suspended without having taken its resume argument.
+    UnusedResumeArg,
+
+    /// A non-`closable` yield point was closed.
+    UnexpectedClose,
+}
+
+#[allow(clippy::needless_arbitrary_self_type)]
+impl ::core::fmt::Display for Misusage {
+    fn fmt (self: &'_ Self, f: &'_ mut ::core::fmt::Formatter<'_>)
+      -> ::core::fmt::Result
+    {
+        write!(f, "Misusage of a `YieldSlot`: {}", match *self {
+            | Misusage::SlotNotEmpty => "slot was not empty",
+            | Misusage::NotEmptyOnResume => {
+                "`transfer_box` was not empty when resuming"
+            },
+            | Misusage::IncorrectPoll => "incorrect poll",
+            | Misusage::IncorrectTakeInitialArg => {
+                "incorrect `take_initial_arg()`"
+            },
+            | Misusage::MissingYieldedItem => {
+                "missing `YieldedItem` in `transfer_box`"
+            },
+            | Misusage::UnusedResumeArg => {
+                "unused `ResumeArg` in `transfer_box`"
+            },
+            | Misusage::UnexpectedClose => "unexpected close",
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+impl ::std::error::Error for Misusage {}
+
+/// The signal a `closable` generator gets, from its `yield_!`s, when being
+/// [`.close()`][`GeneratorFn::close`]d.
+///
+/// See [`GeneratorFn::close`] for more info.
+#[derive(
+    Debug,
+    Clone, Copy,
+    PartialEq, Eq,
+    Hash,
+)]
+pub
+struct Closed;
+
+#[allow(clippy::needless_arbitrary_self_type)]
+impl ::core::fmt::Display for Closed {
+    fn fmt (self: &'_ Self, f: &'_ mut ::core::fmt::Formatter<'_>)
+      -> ::core::fmt::Result
+    {
+        f.write_str("the generator is being closed")
+    }
+}
+
+#[cfg(feature = "std")]
+impl ::std::error::Error for Closed {}
+
+/// The error returned by [`GeneratorFn::close`].
+#[derive(
+    Debug,
+    Clone, Copy,
+    PartialEq, Eq,
+    Hash,
+)]
+pub
+enum CloseError<YieldedItem> {
+    /// The generator yielded this value rather than returning, thus ignoring
+    /// the close request: it is left suspended.
+    Ignored(YieldedItem),
+
+    /// The generator previously panicked while being resumed.
+    Poisoned,
+
+    /// The internals of the generator were misused (see
+    /// [`ResumeError::Misusage`]).
+    Misusage(Misusage),
+}
+
+/// The functions usable with [`GeneratorFn::init_scoped`], when implemented
+/// for every `'brand`: those returning a future generic over the `'brand` of
+/// their `YieldSlot`, such as (non-method) `#[generator]`-annotated functions.
+///
+/// This is just an [`FnOnce`] whose returned [`Future`] can be named, so as to
+/// be able to require it for all the `'brand` lifetimes.
+#[allow(clippy::needless_arbitrary_self_type)]
+pub
+trait ScopedGeneratorFn<'brand, YieldedItem, ResumeArg, Args> {
+    /// The value returned by the generator, once completed.
+    type Return;
+
+    /// The `Future` returned by the function.
+    type Future : Future<Output = Self::Return>;
+
+    /// Calls the function.
+    fn call_scoped (
+        self: Self,
+        yield_slot: YieldSlot<'brand, YieldedItem, ResumeArg>,
+        args: Args,
+    ) -> Self::Future
+    ;
+}
+
+impl<'brand, YieldedItem, ResumeArg, Args, G, Fut>
+    ScopedGeneratorFn<'brand, YieldedItem, ResumeArg, Args>
+for
+    G
+where
+    G : FnOnce(YieldSlot<'brand, YieldedItem, ResumeArg>, Args) -> Fut,
+    Fut : Future,
+{
+    type Return = Fut::Output;
+    type Future = Fut;
+
+    #[inline]
+    fn call_scoped (
+        self: G,
+        yield_slot: YieldSlot<'brand, YieldedItem, ResumeArg>,
+        args: Args,
+    ) -> Fut
+    {
+        self(yield_slot, args)
+    }
+}
+
+/// The payload of a panic, as caught by [`GeneratorFn::resume_catching`].
+#[cfg(feature = "std")]
+pub
+type PanicPayload = ::std::boxed::Box<dyn ::core::any::Any + Send + 'static>;
+
 struct GeneratorFnPinProjected<'pin, YieldedItem, F : Future, ResumeArg> {
     item_slot: &'pin ItemSlot<YieldedItem, ResumeArg>,
-    future: Pin<&'pin mut F>,
+    future: Pin<&'pin mut Option<F>>,
+    state: &'pin mut GeneratorFnState,
+    suspended_on_await: &'pin mut bool,
+    return_value: &'pin mut Option<F::Output>,
+}
+
+#[allow(clippy::needless_arbitrary_self_type)]
+impl<YieldedItem, F : Future, ResumeArg>
+    GeneratorFnPinProjected<'_, YieldedItem, F, ResumeArg>
+{
+    /// Polls the body of the generator (which is assumed to be resumable),
+    /// keeping track of its lifecycle: it is `Poisoned` should the body
+    /// panic (or misuse its `YieldSlot`), and `Completed` (with the body
+    /// dropped in place) once it returns.
+    fn poll (
+        self: &'_ mut Self,
+        cx: &'_ mut Context<'_>,
+    ) -> Result<Poll<F::Output>, Misusage>
+    {
+        *self.state = GeneratorFnState::Poisoned;
+        let transfer_box = &self.item_slot.transfer_box;
+        let future = unsafe {
+            // Safety: pinning projection through the `Option`.
+            self.future.as_mut().map_unchecked_mut(|it| {
+                it.as_mut().expect("resumable generators have a body")
+            })
+        };
+        let poll =
+            ::unwind_safe::with_state(false)
+                .try_eval(|returned| {
+                    let poll = future.poll(cx);
+                    *returned = true;
+                    poll
+                })
+                .finally(|returned| if returned.not() {
+                    // The body panicked: discard whatever it may have left in
+                    // the `transfer_box` (_e.g._, an unused `ResumeArg`).
+                    transfer_box.set(TransferBox::Empty);
+                })
+        ;
+        *self.state = match poll {
+            | Poll::Pending => GeneratorFnState::Suspended,
+            | Poll::Ready(_) => {
+                // Drop the body *in place*.
+                self.future.set(None);
+                GeneratorFnState::Completed
+            },
+        };
+        #[cfg(feature = "hardened")] {
+            if let Some(misusage) = self.item_slot.misusage.take() {
+                return Err(self.misused(misusage));
+            }
+        }
+        Ok(poll)
+    }
+
+    /// Resumes the body directly, should it be the [`NativeBody`] of this very
+    /// generator (no `TransferBox` nor `Context` involved), keeping track of
+    /// its lifecycle as [`.poll()`][`Self::poll`] does. Otherwise, the
+    /// `resume_arg` is given back.
+    #[cfg(feature = "native-coroutines")]
+    fn try_resume_native (
+        self: &'_ mut Self,
+        resume_arg: ResumeArg,
+    ) -> Result<GeneratorState<YieldedItem, F::Output>, ResumeArg>
+    {
+        let body = unsafe {
+            // Safety: pinning projection through the `Option`.
+            self.future.as_mut().map_unchecked_mut(|it| {
+                it.as_mut().expect("resumable generators have a body")
+            })
+        };
+        let native = match body.as_native_body() {
+            | Some(native)
+                if ::core::ptr::eq(
+                    native.item_slot,
+                    (self.item_slot as *const ItemSlot<_, _>).cast(),
+                )
+            => native,
+            | _ => return Err(resume_arg),
+        };
+        *self.state = GeneratorFnState::Poisoned;
+        let mut resume_arg = Some(resume_arg);
+        let mut state = None::<GeneratorState<YieldedItem, F::Output>>;
+        unsafe {
+            // Safety: `F` is a `NativeBody` (whose `Future::Output` is its
+            // `Return` type) holding a `YieldSlot` to this very `ItemSlot`
+            // (an initialized `GeneratorFn` is pinned, so no other live
+            // `ItemSlot` may share its address), whose `YieldedItem` and
+            // `ResumeArg` types are thus ours.
+            let resume_arg: *mut Option<ResumeArg> = &mut resume_arg;
+            let state_ptr: *mut Option<_> = &mut state;
+            (native.resume)(native.body, resume_arg.cast(), state_ptr.cast());
+        }
+        let state = state.expect("native bodies write their state");
+        *self.state = match state {
+            | GeneratorState::Yielded(_) => GeneratorFnState::Suspended,
+            | GeneratorState::Returned(_) => {
+                // Drop the body *in place*.
+                self.future.set(None);
+                GeneratorFnState::Completed
+            },
+        };
+        Ok(state)
+    }
+
+    /// Starts a resume by putting `transfer` in the (empty) `transfer_box`.
+    fn start_resume (
+        self: &'_ mut Self,
+        transfer: TransferBox<YieldedItem, ResumeArg>,
+    ) -> Result<(), Misusage>
+    {
+        let prev = self.item_slot.transfer_box.replace(transfer);
+        check!(
+            matches!(prev, TransferBox::Empty),
+            Misusage::NotEmptyOnResume,
+        )
+        .map_err(|misusage| self.misused(misusage))
+    }
+
+    /// Reports a `misusage`, poisoning the generator: it is returned with the
+    /// `hardened` feature, and panicked with otherwise.
+    fn misused (self: &'_ mut Self, misusage: Misusage)
+      -> Misusage
+    {
+        *self.state = GeneratorFnState::Poisoned;
+        self.item_slot.transfer_box.set(TransferBox::Empty);
+        if cfg!(feature = "hardened").not() {
+            panic!("{}", misusage);
+        }
+        misusage
+    }
 }
 
 impl<YieldedItem, F : Future, ResumeArg>
@@ -328,15 +1136,56 @@ impl<YieldedItem, F : Future, ResumeArg>
             let this = self.get_unchecked_mut();
             GeneratorFnPinProjected {
                 item_slot: &this.item_slot,
-                future: Pin::new_unchecked(
-                    this.future
-                        .as_mut()
-                        .expect("You must init a GeneratorFn before using it!")
-                ),
+                future: Pin::new_unchecked(&mut this.future),
+                state: &mut this.state,
+                suspended_on_await: &mut this.suspended_on_await,
+                return_value: &mut this.return_value,
             }
         }
     }
 
+    /// Queries the current [lifecycle state][`GeneratorFnState`] of the
+    /// generator.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ::next_gen::{generator_fn::GeneratorFnState, prelude::*};
+    ///
+    /// #[generator(yield(u8))]
+    /// fn once ()
+    /// {
+    ///     yield_!(42);
+    /// }
+    ///
+    /// mk_gen!(let mut generator = once());
+    /// assert_eq!(generator.state(), GeneratorFnState::Unstarted);
+    /// generator.as_mut().resume(());
+    /// assert_eq!(generator.state(), GeneratorFnState::Suspended);
+    /// generator.as_mut().resume(());
+    /// assert_eq!(generator.state(), GeneratorFnState::Completed);
+    /// ```
+    #[inline]
+    pub
+    fn state (self: &'_ GeneratorFn<YieldedItem, F, ResumeArg>)
+      -> GeneratorFnState
+    {
+        self.state
+    }
+
+    fn check_resumable (self: &'_ GeneratorFn<YieldedItem, F, ResumeArg>)
+      -> Result<(), ResumeError>
+    {
+        match self.state {
+            | GeneratorFnState::Unstarted
+            | GeneratorFnState::Suspended
+            => Ok(()),
+            | GeneratorFnState::Empty => Err(ResumeError::Empty),
+            | GeneratorFnState::Completed => Err(ResumeError::Completed),
+            | GeneratorFnState::Poisoned => Err(ResumeError::Poisoned),
+        }
+    }
+
     /// Reserves memory for an empty generator; to be [`Pin`]-ned afterwards.
     ///
     /// Splitting the initial creation of the `GeneratorFn` with its
@@ -353,8 +1202,14 @@ impl<YieldedItem, F : Future, ResumeArg>
             item_slot: ItemSlot {
                 transfer_box: TransferBox::Empty.into(),
                 yield_slot_dropped: false.into(),
+                closable: false.into(),
+                #[cfg(feature = "hardened")]
+                misusage: None.into(),
             },
             future: None,
+            state: GeneratorFnState::Empty,
+            suspended_on_await: false,
+            return_value: None,
             _pin_sensitive: PhantomPinned,
         }
     }
@@ -369,48 +1224,118 @@ impl<YieldedItem, F : Future, ResumeArg>
     ///
     /// Note that you do not need to call this function if using the handy
     /// [`mk_gen!`] macro.
+    ///
+    /// # Abort
+    ///
+    /// Should the `YieldSlot` given to `generator_fn` not have been dropped by
+    /// the time this `GeneratorFn` is, the program aborts, since that slot
+    /// would otherwise dangle. This is not possible when using the macros of
+    /// this crate, but [`GeneratorFn::init_scoped`] rules it out statically.
     pub
-    fn init<'pin, 'yield_slot, Args> (
-        self: Pin<&'pin mut GeneratorFn<YieldedItem, F, ResumeArg>>,
+    fn init<'yield_slot, Args> (
+        self: Pin<&'_ mut GeneratorFn<YieldedItem, F, ResumeArg>>,
         generator_fn: impl FnOnce(YieldSlot<'yield_slot, YieldedItem, ResumeArg>, Args) -> F,
         args: Args,
     )
     where
         YieldedItem : 'yield_slot,
         ResumeArg : 'yield_slot,
+    {
+        unsafe {
+            // Safety: the pinning guarantee ensures the soundness of the
+            // lifetime extension: `GeneratorFn` destructor is guaranteed to
+            // run, which performs a runtime check to ensure that the
+            // `yield_slot` has been dropped. If it hasn't, the program
+            // aborts to avoid any potential unsoundness.
+            self.init_with(|yield_slot| generator_fn(yield_slot, args));
+        }
+    }
+
+    /// Same as [`.init()`][`GeneratorFn::init`], but for a `generator_fn`
+    /// whose future is generic over the `'brand` lifetime of its
+    /// (invariant) `YieldSlot` parameter, _e.g._, a (non-method)
+    /// `#[generator]`-annotated function: since that `YieldSlot` can thus not
+    /// escape the future (not even through the returned value), this
+    /// construction is statically sound, and requires no runtime check
+    /// whatsoever (_e.g._, the `YieldSlot` may even be leaked).
+    ///
+    /// This is the construction used by [`mk_gen!`] for such functions (but
+    /// with older compilers, on which it falls back to `.init()`).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ::next_gen::{generator_fn::GeneratorFn, prelude::*};
+    ///
+    /// #[generator(yield(u8))]
+    /// fn countdown (from: u8)
+    /// {
+    ///     for n in (1 ..= from).rev() {
+    ///         yield_!(n);
+    ///     }
+    /// }
+    ///
+    /// let generator = GeneratorFn::empty();
+    /// stack_pinned!(mut generator);
+    /// generator.as_mut().init_scoped(countdown, (3, ));
+    /// assert_eq!(generator.into_iter().collect::<Vec<_>>(), [3, 2, 1]);
+    /// ```
+    pub
+    fn init_scoped<'yield_slot, Args, G> (
+        mut self: Pin<&'_ mut GeneratorFn<YieldedItem, F, ResumeArg>>,
+        generator_fn: G,
+        args: Args,
+    )
+    where
+        G : for<'brand>
+            ScopedGeneratorFn<'brand, YieldedItem, ResumeArg, Args, Return = F::Output>
+        ,
+        G : FnOnce(YieldSlot<'yield_slot, YieldedItem, ResumeArg>, Args) -> F,
+    {
+        unsafe {
+            // Safety: since `G` is generic over the `'brand` of the
+            // `YieldSlot`, and `'brand` is invariant, the latter cannot
+            // escape `F`, which is dropped before the `ItemSlot`.
+            self.as_mut().init_with(|yield_slot| {
+                generator_fn(yield_slot, args)
+            });
+        }
+        // No need for the `Drop` check.
+        self.item_slot.yield_slot_dropped.set(true);
+    }
+
+    /// # Safety
+    ///
+    /// The `YieldSlot` given to `make_future` must not be usable once this
+    /// `GeneratorFn` is dropped, since it points to its `ItemSlot`.
+    unsafe
+    fn init_with<'yield_slot> (
+        self: Pin<&'_ mut GeneratorFn<YieldedItem, F, ResumeArg>>,
+        make_future: impl FnOnce(YieldSlot<'yield_slot, YieldedItem, ResumeArg>) -> F,
+    )
     {
         assert!(
-            self.future.is_none(),
+            self.state == GeneratorFnState::Empty,
             "GeneratorFn cannot be initialized multiple times!",
         );
-        unsafe {
-            // # Safety
-            //
-            //   - This is a pinning projection except for the `future` field,
-            //     to which it gets raw "unlimited" access. This is safe because
-            //     the field cannot have been pinned yet (given the API).
-            //
-            //   - The pinning guarantee ensures the soundness of the lifetime
-            //     extension: `GeneratorFn` destructor is guaranteed to run,
-            //     which performs a runtime check to ensure that the
-            //     `yield_slot` has been dropped. If it hasn't, the program
-            //     aborts to avoid any potential unsoundness.
-            let this = self.get_unchecked_mut();
-            let yield_slot =
-                YieldSlot::new(
-                    ::core::mem::transmute::<
-                        &'pin ItemSlot<YieldedItem, ResumeArg>,
-                        &'yield_slot ItemSlot<YieldedItem, ResumeArg>,
-                    >(
-                        &this.item_slot
-                    )
-                )
-            ;
-            this.future = Some(generator_fn(yield_slot, args));
-        }
+        // Safety: this is a pinning projection except for the `future` field,
+        // to which it gets raw "unlimited" access. This is safe because the
+        // field cannot have been pinned yet (given the API).
+        let this = self.get_unchecked_mut();
+        let yield_slot = YieldSlot::new(&this.item_slot);
+        this.future = Some(make_future(yield_slot));
+        this.state = GeneratorFnState::Unstarted;
     }
 
     /// Associated method version of [`Generator::resume`].
+    ///
+    /// # Panics
+    ///
+    /// If the generator is not [resumable][`GeneratorFn::try_resume`]. In
+    /// particular, once it has [returned][`GeneratorState::Returned`], any
+    /// further `resume` deterministically panics with a dedicated message,
+    /// without ever polling the (already dropped) body of the generator
+    /// again.
     #[inline]
     pub
     fn resume (
@@ -420,6 +1345,220 @@ impl<YieldedItem, F : Future, ResumeArg>
     {
         <Self as Generator<ResumeArg>>::resume(self, resume_arg)
     }
+
+    /// Python-style `close()`: cancels the generator, letting a `closable`
+    /// one observe it and clean up.
+    ///
+    /// Within a `#[generator(yield(…), closable)]`-annotated function, each
+    /// `yield_!` evaluates to a `Result<ResumeArg, `[`Closed`]`>`: `close`
+    /// resumes the generator suspended at such a `yield_!` with an
+    /// `Err(Closed)`, so that it may run some cleanup (or even `yield_!` a
+    /// final value) and return. Then:
+    ///
+    ///   - if the generator returns, its return value is surfaced, as
+    ///     `Ok(Some(…))`;
+    ///
+    ///   - if it `yield_!`s instead, that value is reported as having
+    ///     [`Ignored`][`CloseError::Ignored`] the request, and the generator
+    ///     is left suspended, so that it may be resumed or closed again.
+    ///
+    /// In all the other cases, _i.e._, for an [`Unstarted`] generator, or one
+    /// suspended somewhere else (_e.g._, a non-`closable` generator, or a
+    /// `yield_from!` delegation), the body is just dropped in place, without
+    /// being resumed, and `Ok(None)` is returned. Closing an already
+    /// [`Completed`] (or [`Empty`]) generator is a no-op, whereas closing a
+    /// [`Poisoned`] one fails with [`CloseError::Poisoned`].
+    ///
+    /// Either way, the generator is [`Completed`] once `Ok` is returned.
+    ///
+    /// [`Empty`]: `GeneratorFnState::Empty`
+    /// [`Unstarted`]: `GeneratorFnState::Unstarted`
+    /// [`Completed`]: `GeneratorFnState::Completed`
+    /// [`Poisoned`]: `GeneratorFnState::Poisoned`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ::next_gen::prelude::*;
+    ///
+    /// #[generator(yield(u32), closable)]
+    /// fn lines_written ()
+    ///   -> &'static str
+    /// {
+    ///     let mut count = 0;
+    ///     loop {
+    ///         count += 1;
+    ///         if yield_!(count).is_err() {
+    ///             // Flush some buffer…
+    ///             return "flushed";
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// mk_gen!(let mut generator = lines_written());
+    /// assert_eq!(generator.as_mut().resume(()), GeneratorState::Yielded(1));
+    /// assert_eq!(generator.as_mut().resume(()), GeneratorState::Yielded(2));
+    /// assert_eq!(generator.as_mut().close(), Ok(Some("flushed")));
+    /// assert_eq!(generator.as_mut().close(), Ok(None));
+    /// ```
+    pub
+    fn close (
+        self: Pin<&'_ mut GeneratorFn<YieldedItem, F, ResumeArg>>,
+    ) -> Result<Option<F::Output>, CloseError<YieldedItem>>
+    {
+        match self.state {
+            | GeneratorFnState::Empty
+            | GeneratorFnState::Completed
+            => return Ok(None),
+            | GeneratorFnState::Poisoned => return Err(CloseError::Poisoned),
+            | GeneratorFnState::Unstarted
+            | GeneratorFnState::Suspended
+            => {},
+        }
+        let mut this = self.project();
+        let closable =
+            *this.state == GeneratorFnState::Suspended
+            && this.item_slot.closable.get()
+            && this.suspended_on_await.not()
+        ;
+        if closable.not() {
+            // Should dropping the body panic.
+            *this.state = GeneratorFnState::Poisoned;
+            // Drop the body *in place*.
+            this.future.set(None);
+            *this.state = GeneratorFnState::Completed;
+            return Ok(None);
+        }
+        this.start_resume(TransferBox::Close).map_err(CloseError::Misusage)?;
+
+        macros::create_context!(cx);
+        match this.poll(&mut cx).map_err(CloseError::Misusage)? {
+            | Poll::Pending => {
+                match TransferBox::take(&this.item_slot.transfer_box)
+                {
+                    | TransferBox::YieldedItem(yielded_item) => {
+                        Err(CloseError::Ignored(yielded_item))
+                    },
+                    | _ => Err(CloseError::Misusage(
+                        this.misused(Misusage::MissingYieldedItem)
+                    )),
+                }
+            },
+
+            | Poll::Ready(value) => {
+                Ok(Some(value))
+            },
+        }
+    }
+
+    /// Like [`.resume()`][`GeneratorFn::resume`], but catching any panic of
+    /// the generator's body, which is then reported as the `Err`or value.
+    ///
+    /// The generator is then [`Poisoned`][`GeneratorFnState::Poisoned`], so
+    /// that any later resume deterministically fails (with an `Err`or whose
+    /// payload is the [`ResumeError`] message, in the case of
+    /// `resume_catching`). This makes it possible to isolate a misbehaving
+    /// generator without tearing down the whole thread.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ::next_gen::{generator_fn::GeneratorFnState, prelude::*};
+    ///
+    /// #[generator(yield(u8))]
+    /// fn faulty ()
+    /// {
+    ///     yield_!(1);
+    ///     panic!("Oops");
+    /// }
+    ///
+    /// # ::std::panic::set_hook(Box::new(|_| ()));
+    /// mk_gen!(let mut generator = faulty());
+    /// let mut next = || generator.as_mut().resume_catching(());
+    /// assert_eq!(next().unwrap(), GeneratorState::Yielded(1));
+    /// let payload = next().unwrap_err();
+    /// assert_eq!(payload.downcast_ref::<&str>(), Some(&"Oops"));
+    /// assert!(next().is_err());
+    /// assert_eq!(generator.state(), GeneratorFnState::Poisoned);
+    /// ```
+    #[cfg(feature = "std")]
+    pub
+    fn resume_catching (
+        self: Pin<&'_ mut GeneratorFn<YieldedItem, F, ResumeArg>>,
+        resume_arg: ResumeArg,
+    ) -> Result<GeneratorState<YieldedItem, F::Output>, PanicPayload>
+    {
+        // The generator is poisoned on panic, so no broken invariant can be
+        // observed afterwards.
+        ::std::panic::catch_unwind(::std::panic::AssertUnwindSafe(|| {
+            self.resume(resume_arg)
+        }))
+    }
+
+    /// Non-panicking version of [`.resume()`][`GeneratorFn::resume`]: returns
+    /// an `Err`or if the generator is [`Empty`], [`Completed`], or
+    /// [`Poisoned`] (rather than [`Unstarted`] or [`Suspended`]).
+    ///
+    /// [`Empty`]: `GeneratorFnState::Empty`
+    /// [`Completed`]: `GeneratorFnState::Completed`
+    /// [`Poisoned`]: `GeneratorFnState::Poisoned`
+    /// [`Unstarted`]: `GeneratorFnState::Unstarted`
+    /// [`Suspended`]: `GeneratorFnState::Suspended`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ::next_gen::{generator_fn::ResumeError, prelude::*};
+    ///
+    /// #[generator(yield(u8))]
+    /// fn once ()
+    ///   -> &'static str
+    /// {
+    ///     yield_!(42);
+    ///     "done"
+    /// }
+    ///
+    /// mk_gen!(let mut generator = once());
+    /// let mut next = || generator.as_mut().try_resume(());
+    /// assert_eq!(next(), Ok(GeneratorState::Yielded(42)));
+    /// assert_eq!(next(), Ok(GeneratorState::Returned("done")));
+    /// assert_eq!(next(), Err(ResumeError::Completed));
+    /// assert_eq!(next(), Err(ResumeError::Completed));
+    /// ```
+    pub
+    fn try_resume (
+        self: Pin<&'_ mut GeneratorFn<YieldedItem, F, ResumeArg>>,
+        resume_arg: ResumeArg,
+    ) -> Result<GeneratorState<YieldedItem, F::Output>, ResumeError>
+    {
+        self.check_resumable()?;
+        let mut this = self.project();
+        #[cfg(feature = "native-coroutines")]
+        let resume_arg = match this.try_resume_native(resume_arg) {
+            | Ok(state) => return Ok(state),
+            | Err(resume_arg) => resume_arg,
+        };
+        this.start_resume(TransferBox::ResumeArg(resume_arg))?;
+
+        macros::create_context!(cx);
+        Ok(match this.poll(&mut cx)? {
+            | Poll::Pending => {
+                match TransferBox::take(&this.item_slot.transfer_box)
+                {
+                    | TransferBox::YieldedItem(yielded_item) => {
+                        GeneratorState::Yielded(yielded_item)
+                    },
+                    | _ => return Err(
+                        this.misused(Misusage::MissingYieldedItem).into()
+                    ),
+                }
+            },
+
+            | Poll::Ready(value) => {
+                GeneratorState::Returned(value)
+            },
+        })
+    }
 }
 
 impl<YieldedItem, F : Future, ResumeArg>
@@ -431,35 +1570,150 @@ for
 
     type Return = F::Output;
 
+    /// See [`GeneratorFn::resume`] for the panics.
     fn resume (
         self: Pin<&'_ mut Self>,
         resume_arg: ResumeArg,
     ) -> GeneratorState<YieldedItem, F::Output>
     {
-        let this = self.project(); // panics if uninit
-        let transfer_box = &this.item_slot.transfer_box;
-        let prev = transfer_box.replace(TransferBox::ResumeArg(resume_arg));
-        debug_assert!(
-            matches!(prev, TransferBox::Empty),
-            "When starting a resume, `TransferBox` is empty",
-        );
+        match self.try_resume(resume_arg) {
+            | Ok(state) => state,
+            | Err(err) => panic!("{}", err),
+        }
+    }
+}
 
-        macros::create_context!(cx);
-        match this.future.poll(&mut cx) {
+impl<YieldedItem, F : Future>
+    AsyncGenerator
+for
+    GeneratorFn<YieldedItem, F, ()>
+{
+    type Yield = YieldedItem;
+
+    type Return = F::Output;
+
+    /// Panics in the same situations as [`GeneratorFn::resume`] (see
+    /// [`GeneratorFn::try_poll_resume`] for a non-panicking version).
+    fn poll_resume (
+        self: Pin<&'_ mut Self>,
+        cx: &'_ mut Context<'_>,
+    ) -> Poll<GeneratorState<YieldedItem, F::Output>>
+    {
+        match self.try_poll_resume(cx) {
+            | Poll::Ready(Ok(state)) => Poll::Ready(state),
+            | Poll::Ready(Err(err)) => panic!("{}", err),
+            | Poll::Pending => Poll::Pending,
+        }
+    }
+}
+
+impl<YieldedItem, F : Future>
+    GeneratorFn<YieldedItem, F, ()>
+{
+    /// Takes the value the generator returned while being iterated over,
+    /// if any.
+    ///
+    /// Since an [`Iterator`] can only yield `YieldedItem`s, iterating over a
+    /// pinned `GeneratorFn` stashes its return value, to be retrieved
+    /// afterwards by this method.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ::next_gen::prelude::*;
+    ///
+    /// #[generator(yield(u8))]
+    /// fn countdown (n: u8)
+    ///   -> &'static str
+    /// {
+    ///     for i in (1 ..= n).rev() {
+    ///         yield_!(i);
+    ///     }
+    ///     "Liftoff!"
+    /// }
+    ///
+    /// mk_gen!(let mut generator = countdown(3));
+    /// assert_eq!(generator.by_ref().collect::<Vec<_>>(), [3, 2, 1]);
+    /// // Fused: iterating again does not panic.
+    /// assert_eq!(generator.next(), None);
+    /// assert_eq!(generator.as_mut().take_return(), Some("Liftoff!"));
+    /// assert_eq!(generator.as_mut().take_return(), None);
+    /// ```
+    pub
+    fn take_return (self: Pin<&'_ mut GeneratorFn<YieldedItem, F, ()>>)
+      -> Option<F::Output>
+    {
+        self.project().return_value.take()
+    }
+
+    /// The fused `Iterator::next` of pinned `GeneratorFn`s.
+    pub(in crate)
+    fn iter_next (self: Pin<&'_ mut GeneratorFn<YieldedItem, F, ()>>)
+      -> Option<YieldedItem>
+    {
+        if let GeneratorFnState::Completed = self.state {
+            return None;
+        }
+        let mut this = self;
+        match this.as_mut().resume(()) {
+            | GeneratorState::Yielded(item) => Some(item),
+            | GeneratorState::Returned(value) => {
+                *this.project().return_value = Some(value);
+                None
+            },
+        }
+    }
+
+    /// Non-panicking version of [`AsyncGenerator::poll_resume`], akin to
+    /// [`GeneratorFn::try_resume`].
+    pub
+    fn try_poll_resume (
+        self: Pin<&'_ mut GeneratorFn<YieldedItem, F, ()>>,
+        cx: &'_ mut Context<'_>,
+    ) -> Poll<Result<GeneratorState<YieldedItem, F::Output>, ResumeError>>
+    {
+        if let Err(err) = self.check_resumable() {
+            return Poll::Ready(Err(err));
+        }
+        let mut this = self.project();
+        #[cfg(feature = "native-coroutines")] {
+            if let Ok(state) = this.try_resume_native(()) {
+                return Poll::Ready(Ok(state));
+            }
+        }
+        if ::core::mem::replace(this.suspended_on_await, false).not() {
+            let resume_arg = TransferBox::ResumeArg(());
+            if let Err(misusage) = this.start_resume(resume_arg) {
+                return Poll::Ready(Err(misusage.into()));
+            }
+        }
+
+        let poll = match this.poll(cx) {
+            | Ok(poll) => poll,
+            | Err(misusage) => return Poll::Ready(Err(misusage.into())),
+        };
+        match poll {
             | Poll::Pending => {
-                match TransferBox::take(transfer_box)
+                match TransferBox::take(&this.item_slot.transfer_box)
                 {
                     | TransferBox::YieldedItem(yielded_item) => {
-                        return GeneratorState::Yielded(yielded_item);
+                        Poll::Ready(Ok(GeneratorState::Yielded(yielded_item)))
+                    },
+                    // An actual `.await`, which has registered `cx`'s waker.
+                    | TransferBox::Empty => {
+                        *this.suspended_on_await = true;
+                        Poll::Pending
+                    },
+                    | TransferBox::ResumeArg(_) | TransferBox::Close => {
+                        Poll::Ready(Err(
+                            this.misused(Misusage::UnusedResumeArg).into()
+                        ))
                     },
-                    | _ => panic!("{}", misusage!(
-                        "missing `YieldedItem` in `transfer_box`",
-                    )),
                 }
             },
 
             | Poll::Ready(value) => {
-                return GeneratorState::Returned(value);
+                Poll::Ready(Ok(GeneratorState::Returned(value)))
             },
         }
     }
@@ -468,3 +1722,6 @@ for
 #[cfg(feature = "alloc")]
 pub use call_boxed::CallBoxed;
 mod call_boxed;
+
+pub use method_call::GeneratorMethodCall;
+mod method_call;
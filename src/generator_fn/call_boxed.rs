@@ -64,11 +64,12 @@ use super::*;
 /// let _ = gen.as_mut().resume(ResumeArg());
 /// ```
 #[cfg(feature = "alloc")]
+#[allow(clippy::needless_arbitrary_self_type)]
 pub trait CallBoxed<'yield_slot, YieldedItem, ResumeArg, Args> {
-    ///
+    /// The pinned and boxed [`GeneratorFn`] obtained when calling.
     type CallBoxed;
 
-    ///
+    /// Instances the generator in a heap-pinned box, feeding it `args`.
     fn call_boxed (
         self: Self,
         args: Args,
@@ -103,3 +104,53 @@ where
         gen
     }
 }
+
+/// A generator function (coerced to a `fn` pointer, so that its `YieldSlot`,
+/// and thus its yield type, be known) and its arguments, instanced with
+/// [`.call_boxed()`][`CallBoxed::call_boxed`].
+///
+/// Only for `()`-resumed generators: with any other `ResumeArg`, a downstream
+/// crate could implement `Generator<ResumeArg>` for such a tuple, which would
+/// overlap with the impl for all the `Generator`s.
+///
+/// ```rust
+/// use ::next_gen::{generator::IntoGenerator, prelude::*};
+///
+/// #[generator(yield(u8))]
+/// fn countdown (from: u8)
+/// {
+///     for i in (1 ..= from).rev() {
+///         yield_!(i);
+///     }
+/// }
+///
+/// let countdown: fn(_, _) -> _ = countdown;
+/// let generator = (countdown, (2, )).into_generator();
+/// assert_eq!(generator.collect::<Vec<_>>(), [2, 1]);
+/// ```
+#[cfg(feature = "alloc")]
+#[allow(clippy::needless_arbitrary_self_type)]
+impl<'yield_slot, YieldedItem, Args, F>
+    crate::generator::IntoGenerator<()>
+for
+    (fn(YieldSlot<'yield_slot, YieldedItem, ()>, Args) -> F, Args)
+where
+    YieldedItem : 'yield_slot,
+    F : Future,
+{
+    type Yield = YieldedItem;
+
+    type Return = F::Output;
+
+    type IntoGenerator = Pin<::alloc::boxed::Box<
+        GeneratorFn<YieldedItem, F, ()>
+    >>;
+
+    #[inline]
+    fn into_generator (self: Self)
+      -> Self::IntoGenerator
+    {
+        let (generator_fn, args) = self;
+        generator_fn.call_boxed(args)
+    }
+}
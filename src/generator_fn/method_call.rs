@@ -0,0 +1,127 @@
+use super::*;
+
+/// What a [`#[generator]`][gen]-annotated method with a `self` receiver
+/// returns when called: the generator function, along with its packed
+/// arguments, receiver included.
+///
+/// [gen]: `macro@crate::generator`
+///
+/// Indeed, within an `impl` block,
+///
+/// ```rust
+/// # #[cfg(any())] macro_rules! __ {
+/// #[generator(yield(&T))]
+/// fn iter (&self)
+/// # }
+/// ```
+///
+/// gets split into a hidden function taking the receiver within the packed
+/// args tuple (as [`GeneratorFn::init`] expects), and an `fn iter (&self)`
+/// method returning that function along with `(self, )`.
+///
+/// This makes it possible to use method-call syntax with [`mk_gen!`] (and
+/// [`gen_iter!`]), or, when boxing, to directly call `.call_boxed()` on it.
+///
+/// ## Example
+///
+/// ```rust
+/// use ::next_gen::prelude::*;
+///
+/// struct Pairs<T> {
+///     elems: Vec<T>,
+/// }
+///
+/// impl<T> Pairs<T> {
+///     #[generator(yield((&T, &T)))]
+///     fn iter (&self)
+///     {
+///         for (i, a) in self.elems.iter().enumerate() {
+///             for b in &self.elems[i + 1 ..] {
+///                 yield_!((a, b));
+///             }
+///         }
+///     }
+/// }
+///
+/// let pairs = Pairs { elems: vec![1, 2, 3] };
+///
+/// mk_gen!(let iter = pairs.iter());
+/// assert_eq!(
+///     iter.into_iter().collect::<Vec<_>>(),
+///     [(&1, &2), (&1, &3), (&2, &3)],
+/// );
+///
+/// let iter = pairs.iter().call_boxed();
+/// assert_eq!(iter.into_iter().count(), 3);
+/// ```
+#[allow(clippy::needless_arbitrary_self_type)]
+pub
+trait GeneratorMethodCall<'yield_slot, YieldedItem, ResumeArg>
+where
+    YieldedItem : 'yield_slot,
+    ResumeArg : 'yield_slot,
+{
+    /// The packed args, receiver included, that [`Self::GeneratorFn`] expects.
+    type Args;
+
+    /// The value returned by the generator, once completed.
+    type Return;
+
+    /// The `Future` returned by [`Self::GeneratorFn`].
+    type Future : Future<Output = Self::Return>;
+
+    /// The function to [`.init()`][`GeneratorFn::init`] a [`GeneratorFn`]
+    /// with.
+    type GeneratorFn
+    :
+        FnOnce(
+            YieldSlot<'yield_slot, YieldedItem, ResumeArg>,
+            Self::Args,
+        ) -> Self::Future
+    ;
+
+    /// Splits the method call into the generator function and its packed
+    /// args, ready to be fed to [`GeneratorFn::init`].
+    fn into_parts (self: Self)
+      -> (Self::GeneratorFn, Self::Args)
+    ;
+
+    /// Method-call counterpart of [`CallBoxed::call_boxed`].
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn call_boxed (self: Self)
+      -> Pin<::alloc::boxed::Box<
+            GeneratorFn<YieldedItem, Self::Future, ResumeArg>
+        >>
+    where
+        Self : Sized,
+    {
+        let (generator_fn, args) = self.into_parts();
+        let mut gen = ::alloc::boxed::Box::pin(GeneratorFn::empty());
+        gen.as_mut().init(generator_fn, args);
+        gen
+    }
+}
+
+impl<'yield_slot, YieldedItem, ResumeArg, Factory, Args, F>
+    GeneratorMethodCall<'yield_slot, YieldedItem, ResumeArg>
+for
+    (Factory, Args)
+where
+    YieldedItem : 'yield_slot,
+    ResumeArg : 'yield_slot,
+    Factory : FnOnce(YieldSlot<'yield_slot, YieldedItem, ResumeArg>, Args) -> F,
+    F : Future,
+{
+    type Args = Args;
+    type Return = F::Output;
+    type Future = F;
+    type GeneratorFn = Factory;
+
+    #[inline]
+    fn into_parts (self: (Factory, Args))
+      -> (Factory, Args)
+    {
+        self
+    }
+}
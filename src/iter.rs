@@ -1,15 +1,103 @@
 use_prelude!();
-use ::core::ops::{Deref, DerefMut};
+use ::core::{
+    iter::FusedIterator,
+    ops::{Deref, DerefMut},
+};
 
+/// An [`Iterator`] over the values yielded by a pinned [`Generator`] (resumed
+/// with `()`), as obtained with [`.gen_into_iter()`][
+/// `crate::generator::GeneratorExt::gen_into_iter`] or
+/// [`.boxed_gen_into_iter()`][
+/// `crate::generator::GeneratorExt::boxed_gen_into_iter`].
+///
+/// It is [fused][`FusedIterator`]: once the generator has returned, it is not
+/// resumed anymore, and its return value is kept, to be retrieved with
+/// [`.take_return()`][`IterPin::take_return`] (or
+/// [`.into_return()`][`IterPin::into_return`]).
+///
+/// # Example
+///
+/// ```rust
+/// use ::next_gen::prelude::*;
+///
+/// #[derive(Debug, PartialEq)]
+/// enum Stop { Overflow }
+///
+/// #[generator(yield(u8))]
+/// fn powers_of_two ()
+///   -> Stop
+/// {
+///     let mut n: u8 = 1;
+///     loop {
+///         yield_!(n);
+///         n = match n.checked_mul(2) {
+///             | Some(n) => n,
+///             | None => return Stop::Overflow,
+///         };
+///     }
+/// }
+///
+/// mk_gen!(let generator = powers_of_two());
+/// let mut powers = generator.gen_into_iter();
+/// assert_eq!(powers.by_ref().last(), Some(128));
+/// assert_eq!(powers.next(), None);
+/// assert_eq!(powers.into_return(), Some(Stop::Overflow));
+/// ```
 pub
-struct IterPin<P> (
-    pub(in crate) Pin<P>,
-)
+struct IterPin<P>
 where
     P : DerefMut,
     P::Target : Generator<()>,
-;
+{
+    generator: Pin<P>,
+    finished: bool,
+    return_value: Option<<P::Target as Generator<()>>::Return>,
+}
+
+impl<P> IterPin<P>
+where
+    P : DerefMut,
+    P::Target : Generator<()>,
+{
+    /// Iterates over the values yielded by the given pinned `generator`.
+    #[inline]
+    pub
+    fn new (generator: Pin<P>)
+      -> IterPin<P>
+    {
+        IterPin { generator, finished: false, return_value: None }
+    }
+
+    /// The value returned by the generator, once it has returned (and until
+    /// it is taken).
+    #[inline]
+    pub
+    fn take_return (self: &'_ mut IterPin<P>)
+      -> Option<<P::Target as Generator<()>>::Return>
+    {
+        self.return_value.take()
+    }
+
+    /// Owned version of [`.take_return()`][`IterPin::take_return`].
+    #[inline]
+    pub
+    fn into_return (self: IterPin<P>)
+      -> Option<<P::Target as Generator<()>>::Return>
+    {
+        self.return_value
+    }
 
+    /// Gets the generator back.
+    #[inline]
+    pub
+    fn into_inner (self: IterPin<P>)
+      -> Pin<P>
+    {
+        self.generator
+    }
+}
+
+#[allow(clippy::needless_arbitrary_self_type)]
 impl<P> Iterator for IterPin<P>
 where
     P : DerefMut,
@@ -24,13 +112,28 @@ where
     fn next (self: &'_ mut Self)
       -> Option<Self::Item>
     {
-        match self.0.as_mut().resume(()) {
-            | GeneratorState::Yielded(x) => Some(x),
-            | GeneratorState::Returned(_) => None,
+        if self.finished {
+            return None;
+        }
+        match self.generator.as_mut().resume(()) {
+            | GeneratorState::Yielded(it) => Some(it),
+            | GeneratorState::Returned(it) => {
+                self.finished = true;
+                self.return_value = Some(it);
+                None
+            },
         }
     }
 }
 
+impl<P> FusedIterator for IterPin<P>
+where
+    P : DerefMut,
+    P::Target : Generator<()>,
+{}
+
+/// Fused, with the return value stashed for [`GeneratorFn::take_return`].
+#[allow(clippy::needless_arbitrary_self_type)]
 impl<Item, R, F : Future<Output = R>>
     Iterator
 for
@@ -41,13 +144,19 @@ for
     fn next (self: &'_ mut Self)
       -> Option<Self::Item>
     {
-        match self.as_mut().resume(()) {
-            | GeneratorState::Yielded(x) => Some(x),
-            | GeneratorState::Returned(_) => None,
-        }
+        self.as_mut().iter_next()
     }
 }
 
+impl<Item, R, F : Future<Output = R>>
+    FusedIterator
+for
+    Pin<&'_ mut GeneratorFn<Item, F, ()> >
+{}
+
+/// Not fused (nor keeping the return value), since a `dyn Generator` cannot
+/// tell whether it has returned: see [`IterPin`] for that.
+#[allow(clippy::needless_arbitrary_self_type)]
 impl<Item, R>
     Iterator
 for
@@ -60,14 +169,31 @@ for
     fn next (self: &'_ mut Self)
       -> Option<Self::Item>
     {
-        match self.as_mut().resume(()) {
-            | GeneratorState::Yielded(x) => Some(x),
-            | GeneratorState::Returned(_) => None,
-        }
+        self.as_mut().resume(()).yielded()
     }
 }
 
+/// Not fused (see [`IterPin`]).
+#[allow(clippy::needless_arbitrary_self_type)]
+impl<Item, R>
+    Iterator
+for
+    Pin<&'_ mut (
+        dyn '_ + Generator<(), Yield = Item, Return = R> + Send
+    )>
+{
+    type Item = Item;
+
+    fn next (self: &'_ mut Self)
+      -> Option<Self::Item>
+    {
+        self.as_mut().resume(()).yielded()
+    }
+}
+
+/// Fused, with the return value stashed for [`GeneratorFn::take_return`].
 #[cfg(feature = "alloc")]
+#[allow(clippy::needless_arbitrary_self_type)]
 impl<Item, R, F : Future<Output = R>>
     Iterator
 for
@@ -78,14 +204,20 @@ for
     fn next (self: &'_ mut Self)
       -> Option<Self::Item>
     {
-        match self.as_mut().resume(()) {
-            | GeneratorState::Yielded(x) => Some(x),
-            | GeneratorState::Returned(_) => None,
-        }
+        self.as_mut().iter_next()
     }
 }
 
 #[cfg(feature = "alloc")]
+impl<Item, R, F : Future<Output = R>>
+    FusedIterator
+for
+    Pin<::alloc::boxed::Box<GeneratorFn<Item, F, ()>>>
+{}
+
+/// Not fused (see [`IterPin`]).
+#[cfg(feature = "alloc")]
+#[allow(clippy::needless_arbitrary_self_type)]
 impl<Item, R>
     Iterator
 for
@@ -99,9 +231,26 @@ for
     fn next (self: &'_ mut Self)
       -> Option<Self::Item>
     {
-        match self.as_mut().resume(()) {
-            | GeneratorState::Yielded(x) => Some(x),
-            | GeneratorState::Returned(_) => None,
-        }
+        self.as_mut().resume(()).yielded()
+    }
+}
+
+/// Not fused (see [`IterPin`]).
+#[cfg(feature = "alloc")]
+#[allow(clippy::needless_arbitrary_self_type)]
+impl<Item, R>
+    Iterator
+for
+    Pin<::alloc::boxed::Box<
+        dyn '_ + Generator<(), Yield = Item, Return = R> + Send
+    >>
+{
+    type Item = Item;
+
+    #[inline]
+    fn next (self: &'_ mut Self)
+      -> Option<Self::Item>
+    {
+        self.as_mut().resume(()).yielded()
     }
 }
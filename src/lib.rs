@@ -3,9 +3,21 @@
 )]
 #![cfg_attr(feature = "nightly", feature(
     // Nightly features.
-    doc_auto_cfg,
+    coroutine_trait,
+    doc_cfg,
     doc_notable_trait,
 ))]
+#![cfg_attr(feature = "native-coroutines", feature(
+    allow_internal_unstable,
+    decl_macro,
+    min_specialization,
+))]
+#![cfg_attr(feature = "native-coroutines", allow(internal_features))]
+#![cfg_attr(all(test, feature = "nightly"), feature(
+    coroutines,
+    proc_macro_hygiene,
+    stmt_expr_attributes,
+))]
 #![cfg_attr(not(feature = "better-docs"),
     doc = "See [crates.io](https://crates.io/crates/next-gen)"
 )]
@@ -17,6 +29,10 @@
 #![warn(missing_docs)]
 #![deny(unused_must_use)]
 #![doc(test(attr(deny(warnings), allow(unused), deny(unused_must_use))))]
+#![cfg_attr(feature = "native-coroutines", doc(test(attr(
+    feature(coroutines),
+    allow(duplicate_features),
+))))]
 
 #![no_std]
 
@@ -57,21 +73,196 @@ extern crate std;
     // "\n", "# };",
     "\n", "```",
 )))]
+///
+/// # Methods
+///
+/// The attribute can also be applied to methods with a `self` receiver
+/// (within an inherent `impl` block), in which case calling the method
+/// returns an [`impl GeneratorMethodCall`][
+/// `generator_fn::GeneratorMethodCall`], usable with [`mk_gen!`]'s
+/// method-call syntax or through `.call_boxed()`.
+///
+/// # Boxed generators
+///
+/// With the extra `boxed` (resp. `boxed_send`) parameter, the function keeps
+/// its signature, but directly returns a heap-pinned and type-erased
+/// generator: a `Pin<Box<dyn Generator<ResumeArg, Yield = …, Return = …>>>`
+/// (resp. with an extra `+ Send`), as [`.call_boxed()`][
+/// `generator_fn::CallBoxed`] would yield.
+///
+/// This is handy to return generators from trait methods, or to iterate over
+/// them without any further ceremony:
+///
+/// ```rust
+/// use ::next_gen::prelude::*;
+///
+/// #[generator(yield(u8), boxed)]
+/// fn countdown (from: u8)
+/// {
+///     for i in (1 ..= from).rev() {
+///         yield_!(i);
+///     }
+/// }
+///
+/// assert_eq!(countdown(3).collect::<Vec<_>>(), [3, 2, 1]);
+/// ```
+///
+/// # Async generators
+///
+/// With the extra `async` parameter, the body may `.await` actual futures as
+/// well (in between `yield_!`s), provided the generator is then polled through
+/// [`AsyncGenerator::poll_resume`][`async_generator::AsyncGenerator`] (or as a
+/// `Stream`), rather than [`Generator::resume`][`generator::Generator`].
+/// Such generators cannot take `resume(…)` args.
+///
+/// # Closable generators
+///
+/// With the extra `closable` parameter, each `yield_!` evaluates to a
+/// `Result<ResumeArg, `[`Closed`][`generator_fn::Closed`]`>`, the `Err` case
+/// signaling that the generator is being [`.close()`][
+/// `generator_fn::GeneratorFn::close`]d, Python-style: the body then gets a
+/// chance to clean up before returning.
+///
+/// # Throwing errors
+///
+/// With the extra `throws(E)` parameter, the generator is resumed with
+/// `Result<ResumeArg, E>`s instead, so that an error can be injected at its
+/// current `yield_!` point with [`.throw(err)`][`generator::GeneratorExt::throw`]
+/// (sugar for `.resume(Err(err))`). Each `yield_!` thus evaluates to a
+/// `Result<ResumeArg, E>`, and `?` can propagate the thrown error as the
+/// generator's return value, which must hence be a `Result<_, impl From<E>>`
+/// (an error thrown before the generator even started is returned right away).
+///
+/// # `Send` generators
+///
+/// Whether a generator is `Send` depends on what its body holds across its
+/// `yield_!`s, which is otherwise only checked where the generator is sent to
+/// another thread. With the extra `send` parameter, this is checked at the
+/// definition site instead, with the compiler pointing at the offending local:
+///
+/// ```rust
+/// use ::next_gen::prelude::*;
+///
+/// #[generator(yield(u32), send)]
+/// fn numbers (count: u32)
+/// {
+///     for i in 0 .. count {
+///         yield_!(i);
+///     }
+/// }
+///
+/// let generator = numbers.call_boxed((3, ));
+/// ::std::thread::spawn(move || {
+///     assert_eq!(generator.into_iter().sum::<u32>(), 3);
+/// }).join().unwrap();
+/// ```
+///
+/// Combined with `boxed`, it is the same as `boxed_send`.
+///
+/// # Closures
+///
+/// The attribute can also be applied to closures, with the closure parameters
+/// then playing the role of the function parameters:
+///
+/// ```rust
+/// # #[cfg(any())] macro_rules! __ {
+/// let countdown = #[generator(yield(u8))] |from: u8| {
+///     for i in (1 ..= from).rev() {
+///         yield_!(i);
+///     }
+/// };
+/// mk_gen!(let generator = countdown(3));
+/// # }
+/// ```
+///
+/// Note that, as of this writing, attributes on expressions are unstable
+/// (`#![feature(stmt_expr_attributes, proc_macro_hygiene)]`); on stable Rust,
+/// see [`gen!`] instead.
 pub use ::next_gen_proc_macros::generator;
 
-pub use {
-    // ::{
-    //     next_gen_proc_macros::generator,
-    // },
-    // self::{
-    //     // coroutine::*,
-    //     // generator::*,
-    //     // ops::{Generator, GeneratorState},
-    // },
-};
+/// Defines a generator in expression position, capturing its environment as
+/// a closure would.
+///
+/// # Usage
+///
+/// > `gen!(yield(<yield type>) $(, resume(<resume type>) $(as <pat>)?)? => $(move)? { … })`
+///
+/// The [`#[generator]`][`macro@generator`] parameters are followed by `=>`
+/// and the body, wherein `yield_!` (and `yield_from!`) can be used. The result
+/// can then be used as a `#[generator]` function that takes no arguments, _e.g._,
+/// with [`mk_gen!`], [`gen_iter!`], or
+/// [`.call_boxed(())`][`generator_fn::CallBoxed`].
+///
+/// # Example
+///
+/// ```rust
+/// use ::next_gen::prelude::*;
+///
+/// let mut total = 0;
+/// {
+///     let evens = gen!(yield(u8) => {
+///         for i in 0 .. 10 {
+///             if i % 2 == 0 {
+///                 total += 1;
+///                 yield_!(i);
+///             }
+///         }
+///     });
+///     mk_gen!(let generator = evens());
+///     assert_eq!(generator.into_iter().collect::<Vec<_>>(), [0, 2, 4, 6, 8]);
+/// }
+/// assert_eq!(total, 5);
+/// ```
+pub use ::next_gen_proc_macros::gen;
+
+/// Transforms a function with `yield_!` calls into a [coroutine][
+/// `coroutine::Coroutine`]: a generator with resume arguments.
+///
+/// # Usage
+///
+/// > `#[next_gen::coroutine(<yield type> $(, <other generator params>)*)]`
+///
+/// This is sugar for [`#[generator(yield(…), resume(…) as …)]`][
+/// `macro@generator`], wherein one of the function parameters, of the form
+/// `<pat> @ yield_: <resume type>` (or just `yield_: <resume type>`),
+/// describes the resume argument: its type, and the pattern binding the
+/// initial resume argument (the one the coroutine is started with).
+///
+/// The other parameters of `#[generator]` (_e.g._, `boxed`) can follow the
+/// yield type.
+///
+/// Note that a bare `#[coroutine]` would clash with the (unstable) built-in
+/// attribute of the same name, hence the need to qualify the path, and why
+/// this attribute is not part of the [`prelude`].
+///
+/// # Example
+///
+/// ```rust
+/// use ::next_gen::prelude::*;
+///
+/// #[next_gen::coroutine(u64)]
+/// fn running_sum (mut sum @ yield_: u64)
+/// {
+///     loop {
+///         sum += yield_!(sum);
+///     }
+/// }
+///
+/// mk_coro!(let mut coroutine = running_sum());
+/// let mut add = |n| coroutine.as_mut().resume_with(n);
+/// assert_eq!(add(1), GeneratorState::Yielded(1));
+/// assert_eq!(add(2), GeneratorState::Yielded(3));
+/// assert_eq!(add(3), GeneratorState::Yielded(6));
+/// ```
+pub use ::next_gen_proc_macros::coroutine;
 
+pub mod async_generator;
+pub mod coroutine;
+pub mod gen_from_async;
 pub mod generator;
 pub mod generator_fn;
+#[cfg(feature = "nightly")]
+pub mod native;
 pub mod prelude;
 
 mod iter;
@@ -79,6 +270,12 @@ mod public_macros;
 mod utils;
 mod waker;
 
+#[cfg(feature = "ui-tests")]
+#[cfg_attr(feature = "ui-tests",
+    cfg_attr(all(), doc = include_str!("compile_fail_tests.md")),
+)]
+mod compile_fail_tests {}
+
 #[path = "macro_internals.rs"]
 #[doc(hidden)] /** Not part of the public API */ pub
 mod __;
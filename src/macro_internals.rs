@@ -10,6 +10,240 @@ pub use {
     },
 };
 
+use crate::generator_fn::GeneratorFn;
+
+/// Nudges a closure into having the signature of a `#[generator]` function.
+#[inline]
+pub
+fn generator_closure<'yield_slot, YieldedItem, ResumeArg, Args, Ret, Closure, F> (
+    closure: Closure,
+) -> Closure
+where
+    Closure : FnOnce(
+        __Internals_YieldSlot_DoNotUse__<'yield_slot, YieldedItem, ResumeArg>,
+        Args,
+    ) -> F,
+    F : Future<Output = Ret>,
+    YieldedItem : 'yield_slot,
+    ResumeArg : 'yield_slot,
+{
+    closure
+}
+
+/// Autoref-based specialization to [`.init_scoped()`] a `GeneratorFn` whenever
+/// possible (_e.g._, for `#[generator]` functions), and to fall back to
+/// [`.init()`] otherwise (_e.g._, for `gen!` closures).
+///
+/// Usage: `(&&&InitScoped::new(generator_fn)).__init(pinned, args)`.
+///
+/// The generator closures, recognizable by their [`ClosureFuture`], are
+/// directly routed to [`.init()`] beforehand. Other generator functions which
+/// cannot be [`.init_scoped()`] (_e.g._, hand-written ones) rely on the
+/// `for<'brand>` bound being ruled out when picking the method, which older
+/// compilers do not do: with these (as probed by the build script), the
+/// scoped arm is not provided, and the plain [`.init()`] one is always used.
+///
+/// [`.init_scoped()`]: crate::generator_fn::GeneratorFn::init_scoped
+/// [`.init()`]: crate::generator_fn::GeneratorFn::init
+pub
+struct InitScoped<G> (
+    ::core::cell::Cell<Option<G>>,
+);
+
+impl<G> InitScoped<G> {
+    #[inline]
+    pub
+    fn new (generator_fn: G)
+      -> InitScoped<G>
+    {
+        InitScoped(::core::cell::Cell::new(Some(generator_fn)))
+    }
+
+    #[inline]
+    fn take (self: &'_ InitScoped<G>)
+      -> G
+    {
+        self.0.take().expect("`__init()` called twice")
+    }
+}
+
+#[allow(clippy::needless_arbitrary_self_type)]
+pub
+trait InitViaScoped<'yield_slot, YieldedItem, ResumeArg, Args, F>
+where
+    F : Future,
+{
+    fn __init (
+        self: Self,
+        generator: Pin<&'_ mut GeneratorFn<YieldedItem, F, ResumeArg>>,
+        args: Args,
+    )
+    ;
+}
+
+#[cfg(next_gen_scoped_autoref)]
+#[allow(clippy::needless_arbitrary_self_type)]
+impl<'yield_slot, YieldedItem, ResumeArg, Args, F, G>
+    InitViaScoped<'yield_slot, YieldedItem, ResumeArg, Args, F>
+for
+    &'_ &'_ InitScoped<G>
+where
+    F : Future,
+    G : for<'brand>
+        crate::generator_fn::ScopedGeneratorFn<
+            'brand, YieldedItem, ResumeArg, Args,
+            Return = F::Output,
+        >
+    ,
+    G : FnOnce(__Internals_YieldSlot_DoNotUse__<'yield_slot, YieldedItem, ResumeArg>, Args) -> F,
+{
+    #[inline]
+    fn __init (
+        self: Self,
+        generator: Pin<&'_ mut GeneratorFn<YieldedItem, F, ResumeArg>>,
+        args: Args,
+    )
+    {
+        generator.init_scoped(self.take(), args)
+    }
+}
+
+#[allow(clippy::needless_arbitrary_self_type)]
+pub
+trait InitViaInit<'yield_slot, YieldedItem, ResumeArg, Args, F>
+where
+    F : Future,
+{
+    fn __init (
+        self: Self,
+        generator: Pin<&'_ mut GeneratorFn<YieldedItem, F, ResumeArg>>,
+        args: Args,
+    )
+    ;
+}
+
+#[allow(clippy::needless_arbitrary_self_type)]
+impl<'yield_slot, YieldedItem, ResumeArg, Args, F, G>
+    InitViaInit<'yield_slot, YieldedItem, ResumeArg, Args, ClosureFuture<F>>
+for
+    &'_ &'_ &'_ InitScoped<G>
+where
+    F : Future,
+    G : FnOnce(
+        __Internals_YieldSlot_DoNotUse__<'yield_slot, YieldedItem, ResumeArg>,
+        Args,
+    ) -> ClosureFuture<F>,
+    YieldedItem : 'yield_slot,
+    ResumeArg : 'yield_slot,
+{
+    #[inline]
+    fn __init (
+        self: Self,
+        generator: Pin<&'_ mut GeneratorFn<
+            YieldedItem,
+            ClosureFuture<F>,
+            ResumeArg,
+        >>,
+        args: Args,
+    )
+    {
+        generator.init(self.take(), args)
+    }
+}
+
+#[allow(clippy::needless_arbitrary_self_type)]
+impl<'yield_slot, YieldedItem, ResumeArg, Args, F, G>
+    InitViaInit<'yield_slot, YieldedItem, ResumeArg, Args, F>
+for
+    &'_ InitScoped<G>
+where
+    F : Future,
+    G : FnOnce(__Internals_YieldSlot_DoNotUse__<'yield_slot, YieldedItem, ResumeArg>, Args) -> F,
+    YieldedItem : 'yield_slot,
+    ResumeArg : 'yield_slot,
+{
+    #[inline]
+    fn __init (
+        self: Self,
+        generator: Pin<&'_ mut GeneratorFn<YieldedItem, F, ResumeArg>>,
+        args: Args,
+    )
+    {
+        generator.init(self.take(), args)
+    }
+}
+
+/// The future returned by the generator closures, so that `mk_gen!` can tell
+/// them apart from the `#[generator]` functions (see [`InitScoped`]).
+pub
+struct ClosureFuture<F> (
+    pub F,
+);
+
+impl<F : Future>
+    Future
+for
+    ClosureFuture<F>
+{
+    type Output = F::Output;
+
+    #[inline]
+    fn poll (
+        self: Pin<&'_ mut ClosureFuture<F>>,
+        cx: &'_ mut Context<'_>,
+    ) -> Poll<F::Output>
+    {
+        unsafe {
+            // Safety: structural pinning of the only field (no `Drop` nor
+            // `Unpin` impls).
+            self.map_unchecked_mut(|ClosureFuture(it)| it)
+        }
+        .poll(cx)
+    }
+}
+
+/// `#[generator(…, send)]`: statically asserts that the body is `Send`.
+#[inline]
+pub
+fn assert_send<F : Future + Send> (body: F)
+  -> F
+{
+    body
+}
+
+/// Using `.into_inner()` on this forces an `async` block to capture it by
+/// value, even when it is `Copy`.
+pub
+struct Moved<T> (
+    pub T,
+);
+
+impl<T> Moved<T> {
+    #[inline]
+    pub
+    fn into_inner (self: Moved<T>)
+      -> T
+    {
+        self.0
+    }
+}
+
+/// Never actually called: its only purpose is to have a `return` of the right
+/// type to start an `async` block with, so as to drive the inference of the
+/// latter.
+pub
+fn unreachable<Ret> ()
+  -> Ret
+{
+    unreachable!()
+}
+
+/// Helper to make an `impl Trait` capture some type (and its lifetimes).
+pub
+trait Captures<T : ?Sized> {}
+
+impl<T : ?Sized, U : ?Sized> Captures<T> for U {}
+
 #[cfg(feature = "alloc")]
 pub extern crate alloc;
 
@@ -19,3 +253,9 @@ pub extern crate std;
 macros::export_hidden_macros! {
     /* … */
 }
+
+#[cfg(feature = "native-coroutines")]
+pub use native_coroutine::{__native_coroutine__, __native_yield__};
+#[cfg(feature = "native-coroutines")]
+#[path = "macro_internals/native_coroutine.rs"]
+mod native_coroutine;
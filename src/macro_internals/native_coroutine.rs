@@ -0,0 +1,29 @@
+// (In its own file, since `macro`s are feature-gated before `cfg`-stripping.)
+
+/// The native coroutine a (non-`async`) generator body lowers to, with the
+/// `native-coroutines` feature, as the `NativeBody` of the generator.
+///
+/// (A def-site hygienic macro, so that `#[coroutine]` unambiguously refers to
+/// the built-in attribute, even if the caller has another one in scope, and
+/// so that the caller does not need to enable `stmt_expr_attributes`.)
+#[allow_internal_unstable(coroutines, stmt_expr_attributes)]
+pub
+macro __native_coroutine__ (
+    $yield_slot:ident, $ResumeArg:ty,
+    [$($capture:tt)?] |$resume_arg_pat:pat| $body:block
+) {{
+    let coroutine =
+        #[coroutine] static $($capture)? |$resume_arg_pat: $ResumeArg| $body
+    ;
+    $yield_slot.__native(coroutine)
+}}
+
+/// The native `yield` of a `yield_!` (or `yield_from!`) within such a body.
+///
+/// (`yield` is gated regardless of `allow_internal_unstable`, hence the caller
+/// having to enable `coroutines` itself.)
+#[allow_internal_unstable(coroutines)]
+pub
+macro __native_yield__ ($value:expr) {{
+    yield $value
+}}
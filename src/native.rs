@@ -0,0 +1,185 @@
+//! Interop with the (unstable) native coroutines of the language, _i.e._,
+//! with <https://doc.rust-lang.org/nightly/core/ops/trait.Coroutine.html>.
+//!
+//! Since this crate's [`Generator`] is a `stable` polyfill of that trait, this
+//! module provides the bridges between the two, so that code using one can be
+//! gradually migrated to the other:
+//!
+//!   - [`GeneratorFn`]s (and `dyn Generator`s) implement
+//!     [`::core::ops::Coroutine`] (and thus, so do the `Pin<&mut …>` and
+//!     `Pin<Box<…>>` pointers to these, courtesy of `::core`);
+//!
+//!   - [`NativeCoroutine`] wraps a native coroutine (_e.g._, a `#[coroutine]`
+//!     closure) so as to make it usable as a [`Generator`];
+//!
+//!   - [`GeneratorState`] and [`CoroutineState`] convert [`From`] one another.
+//!
+//! # Example
+//!
+//! ```rust
+//! #![feature(coroutines, coroutine_trait, stmt_expr_attributes)]
+//!
+//! use ::core::ops::{Coroutine, CoroutineState};
+//! use ::next_gen::{native::NativeCoroutine, prelude::*};
+//!
+//! // From `next_gen` to native.
+//! #[generator(yield(u8))]
+//! fn countdown (from: u8)
+//! {
+//!     for n in (0 ..= from).rev() {
+//!         yield_!(n);
+//!     }
+//! }
+//!
+//! mk_gen!(let mut generator = countdown(1));
+//! assert_eq!(
+//!     Coroutine::resume(generator.as_mut(), ()),
+//!     CoroutineState::Yielded(1),
+//! );
+//!
+//! // From native to `next_gen`.
+//! let coroutine = NativeCoroutine::new(#[coroutine] || {
+//!     yield 1_u8;
+//!     yield 0;
+//! });
+//! stack_pinned!(mut coroutine);
+//! assert_eq!(coroutine.gen_into_iter().collect::<Vec<_>>(), [1, 0]);
+//! ```
+
+use_prelude!();
+use ::core::ops::{Coroutine, CoroutineState};
+
+/// Wrapper around a native coroutine (such as a `#[coroutine]` closure), to
+/// make it usable as a [`Generator`].
+///
+/// See [the module-level documentation][`self`] for an example.
+#[derive(Debug, Clone, Copy, Default)]
+pub
+struct NativeCoroutine<C> (
+    C,
+);
+
+impl<C> NativeCoroutine<C> {
+    /// Wraps the given native coroutine.
+    #[inline]
+    pub
+    fn new<ResumeArg> (coroutine: C)
+      -> NativeCoroutine<C>
+    where
+        C : Coroutine<ResumeArg>,
+    {
+        NativeCoroutine(coroutine)
+    }
+
+    /// Unwraps the native coroutine.
+    #[inline]
+    pub
+    fn into_inner (self: NativeCoroutine<C>)
+      -> C
+    {
+        self.0
+    }
+
+    #[inline]
+    fn project (self: Pin<&'_ mut NativeCoroutine<C>>)
+      -> Pin<&'_ mut C>
+    {
+        unsafe {
+            // Safety: structural pinning of the sole field.
+            self.map_unchecked_mut(|this| &mut this.0)
+        }
+    }
+}
+
+impl<C, ResumeArg>
+    Generator<ResumeArg>
+for
+    NativeCoroutine<C>
+where
+    C : Coroutine<ResumeArg>,
+{
+    type Yield = C::Yield;
+    type Return = C::Return;
+
+    #[inline]
+    fn resume (
+        self: Pin<&'_ mut Self>,
+        resume_arg: ResumeArg,
+    ) -> GeneratorState<Self::Yield, Self::Return>
+    {
+        self.project().resume(resume_arg).into()
+    }
+}
+
+// # BRIDGES WITH `::core::ops::Coroutine`
+impl<YieldedItem, F : Future, ResumeArg>
+    Coroutine<ResumeArg>
+for
+    GeneratorFn<YieldedItem, F, ResumeArg>
+{
+    bridge_impl_deferring_to_generator!();
+}
+
+impl<YieldedItem, Return, ResumeArg>
+    Coroutine<ResumeArg>
+for
+    dyn '_ + Generator<ResumeArg, Yield = YieldedItem, Return = Return>
+{
+    bridge_impl_deferring_to_generator!();
+}
+
+impl<YieldedItem, Return, ResumeArg>
+    Coroutine<ResumeArg>
+for
+    dyn '_ + Generator<ResumeArg, Yield = YieldedItem, Return = Return> + Send
+{
+    bridge_impl_deferring_to_generator!();
+}
+
+// where:
+macro_rules! bridge_impl_deferring_to_generator {() => (
+    type Yield = YieldedItem;
+    type Return = <Self as Generator<ResumeArg>>::Return;
+
+    #[inline]
+    fn resume (
+        self: Pin<&'_ mut Self>,
+        resume_arg: ResumeArg,
+    ) -> CoroutineState<Self::Yield, Self::Return>
+    {
+        <Self as Generator<ResumeArg>>::resume(self, resume_arg).into()
+    }
+)} use bridge_impl_deferring_to_generator;
+
+// # STATE CONVERSIONS
+impl<Yield, Return>
+    From<GeneratorState<Yield, Return>>
+for
+    CoroutineState<Yield, Return>
+{
+    #[inline]
+    fn from (state: GeneratorState<Yield, Return>)
+      -> CoroutineState<Yield, Return>
+    {
+        match state {
+            | GeneratorState::Yielded(it) => CoroutineState::Yielded(it),
+            | GeneratorState::Returned(it) => CoroutineState::Complete(it),
+        }
+    }
+}
+
+impl<Yield, Return>
+    From<CoroutineState<Yield, Return>>
+for
+    GeneratorState<Yield, Return>
+{
+    #[inline]
+    fn from (state: CoroutineState<Yield, Return>)
+      -> GeneratorState<Yield, Return>
+    {
+        match state {
+            | CoroutineState::Yielded(it) => GeneratorState::Yielded(it),
+            | CoroutineState::Complete(it) => GeneratorState::Returned(it),
+        }
+    }
+}
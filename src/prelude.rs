@@ -7,21 +7,44 @@ pub use {
             pin::Pin,
         },
         next_gen_proc_macros::{
+            gen,
             generator,
         },
     },
     crate::{
+        async_generator::{
+            AsyncGenerator,
+        },
+        coroutine::{
+            Coroutine,
+        },
         gen_iter,
+        generator_fn::{
+            GeneratorMethodCall as _,
+        },
         generator::{
             Generator,
             GeneratorExt as _,
             GeneratorState,
         },
+        mk_coro,
         mk_gen,
         stack_pinned,
     },
 };
 
+#[doc(inline)]
+pub use crate::{
+    __yield_from__ as yield_from,
+    __yield__ as yield_,
+};
+
+#[doc(hidden)]
+pub use crate::{
+    __next_gen_yield__,
+    __next_gen_yield_from__,
+};
+
 #[cfg(feature = "alloc")]
 pub use {
     crate::{
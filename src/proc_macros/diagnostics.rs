@@ -0,0 +1,153 @@
+use super::*;
+use ::syn::visit::{self, Visit};
+
+/// Walks the body of a generator to spot the misusages which would otherwise
+/// only be caught at runtime, or with baffling error messages:
+///
+///   - `.await`ing an actual future within a non-`async` generator (which
+///     would panic when resumed);
+///
+///   - `yield_!`ing from within a closure, an `async` block, or a nested item,
+///     since these are not part of the generator's own body.
+///
+/// Returning a value of the wrong type is not checked here, since only the
+/// type checker knows the types of expressions: rather, the body is lowered
+/// with a [`ret_hint`], so that `rustc` reports it on the offending `return`
+/// (or tail expression), against the declared return type.
+pub(in crate)
+fn check_body (
+    block: &'_ Block,
+    is_async: bool,
+) -> Result<()>
+{
+    let mut visitor = CheckBody {
+        is_async,
+        nested_in: None,
+        errors: None,
+    };
+    visitor.visit_block(block);
+    match visitor.errors {
+        | Some(err) => Err(err),
+        | None => Ok(()),
+    }
+}
+
+struct CheckBody {
+    is_async: bool,
+    /// The innermost non-generator-body scope we are in, if any.
+    nested_in: Option<&'static str>,
+    errors: Option<Error>,
+}
+
+impl CheckBody {
+    fn error (
+        self: &'_ mut Self,
+        span: Span,
+        msg: impl ::core::fmt::Display,
+    )
+    {
+        let err = Error::new(span, msg);
+        match self.errors {
+            | Some(ref mut errors) => errors.combine(err),
+            | None => self.errors = Some(err),
+        }
+    }
+
+    fn visit_nested (
+        self: &'_ mut Self,
+        nested_in: &'static str,
+        visit: impl FnOnce(&'_ mut Self),
+    )
+    {
+        let prev = self.nested_in.replace(nested_in);
+        visit(self);
+        self.nested_in = prev;
+    }
+}
+
+/// Nested generators have their own body (and thus, their own checks).
+fn is_generator (attrs: &'_ [Attribute])
+  -> bool
+{
+    attrs.iter().any(|attr| matches!(
+        attr.path.segments.last(),
+        Some(last) if last.ident == "generator" || last.ident == "coroutine"
+    ))
+}
+
+impl Visit<'_> for CheckBody {
+    fn visit_expr_closure (self: &'_ mut Self, closure: &'_ ExprClosure)
+    {
+        if is_generator(&closure.attrs) {
+            return;
+        }
+        self.visit_nested("a closure", |this| {
+            visit::visit_expr_closure(this, closure);
+        });
+    }
+
+    fn visit_expr_async (self: &'_ mut Self, async_block: &'_ ExprAsync)
+    {
+        self.visit_nested("an `async` block", |this| {
+            visit::visit_expr_async(this, async_block);
+        });
+    }
+
+    fn visit_item (self: &'_ mut Self, item: &'_ Item)
+    {
+        if let Item::Fn(ItemFn { ref attrs, .. }) = *item {
+            if is_generator(attrs) {
+                return;
+            }
+        }
+        self.visit_nested("a nested item", |this| {
+            visit::visit_item(this, item);
+        });
+    }
+
+    fn visit_expr_await (self: &'_ mut Self, await_: &'_ ExprAwait)
+    {
+        if self.nested_in.is_none() && self.is_async.not() {
+            let span =
+                await_
+                    .dot_token
+                    .span
+                    .join(await_.await_token.span)
+                    .unwrap_or(await_.await_token.span)
+            ;
+            self.error(span, "\
+                `.await`ing a future is only supported within `async` \
+                generators: use `#[generator(yield(…), async)]`, and then \
+                poll it as an `AsyncGenerator` (or a `Stream`)\
+            ");
+        }
+        visit::visit_expr_await(self, await_);
+    }
+
+    fn visit_macro (self: &'_ mut Self, mac: &'_ Macro)
+    {
+        let name = match mac.path.get_ident() {
+            | Some(ident) => ident.to_string(),
+            | None => return,
+        };
+        match (&*name, self.nested_in) {
+            | ("gen", _) => return,
+            | ("yield_", Some(nested_in)) | ("yield_from", Some(nested_in)) => {
+                self.error(mac.path.span(), format_args!(
+                    "`{}!` cannot be used within {}, \
+                    only within the body of the generator itself",
+                    name, nested_in,
+                ));
+            },
+            | _ => {},
+        }
+        // Best effort: most macros (`yield_!` included) take expressions,
+        // which may themselves `yield_!` or `.await`.
+        if let Ok(exprs) =
+            Punctuated::<Expr, Token![,]>::parse_terminated
+                .parse2(mac.tokens.clone())
+        {
+            exprs.iter().for_each(|expr| self.visit_expr(expr));
+        }
+    }
+}
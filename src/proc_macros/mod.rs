@@ -2,6 +2,11 @@
 //! Use https://docs.rs/next-gen instead.
 // Templated by `cargo-generate` using https://github.com/danielhenrymantilla/proc-macro-template
 #![allow(nonstandard_style, unused_imports)]
+#![allow(
+    clippy::map_identity,
+    clippy::needless_arbitrary_self_type,
+    clippy::redundant_pattern,
+)]
 
 use ::core::{
     mem,
@@ -26,8 +31,10 @@ use ::syn::{*,
     punctuated::Punctuated,
     Result, // Explicitly shadow it
     spanned::Spanned,
+    visit_mut::VisitMut,
 };
 
+mod diagnostics;
 mod utils;
 
 // #[macro_use]
@@ -39,8 +46,41 @@ fn generator (
     input: TokenStream,
 ) -> TokenStream
 {
-    generator_impl(attrs.into(), input.into())
-        .map(|ret| {
+    render(
+        "`#[next_gen::generator]`",
+        generator_impl(attrs.into(), input.into()),
+    )
+}
+
+#[proc_macro_attribute] pub
+fn coroutine (
+    attrs: TokenStream,
+    input: TokenStream,
+) -> TokenStream
+{
+    render(
+        "`#[next_gen::coroutine]`",
+        coroutine_impl(attrs.into(), input.into()),
+    )
+}
+
+#[proc_macro] pub
+fn gen (
+    input: TokenStream,
+) -> TokenStream
+{
+    render(
+        "`next_gen::gen!`",
+        gen_impl(input.into()),
+    )
+}
+
+fn render (
+    macro_name: &'_ str,
+    ret: Result<TokenStream2>,
+) -> TokenStream
+{
+    ret .map(|ret| {
             #[cfg(feature = "verbose-expansions")] {
                 utils::pretty_print_tokenstream(&ret);
             }
@@ -51,7 +91,7 @@ fn generator (
                 err .into_iter()
                     .map(|err| Error::new(
                         err.span(),
-                        format_args!("`#[next_gen::generator]`: {}", err),
+                        format_args!("{}: {}", macro_name, err),
                     ))
             ;
             let mut err = errors.next().unwrap();
@@ -61,95 +101,286 @@ fn generator (
         .into()
 }
 
-fn generator_impl (
-    params: TokenStream2,
-    input: TokenStream2,
-) -> Result<TokenStream2>
-{
-    struct Params {
-        yield_ty: Type,
-        resume: Option<(Type, Option<Pat>)>,
-    }
-    impl Parse for Params {
-        fn parse (input: ParseStream<'_>)
-          -> Result<Params>
-        {
-            mod kw {
-                ::syn::custom_keyword!(resume);
-            }
-            let mut yield_ty: Option<Type> = None;
-            let mut resume: Option<(Type, Option<Pat>)> = None;
-            while input.is_empty().not() {
-                let snoopy = input.lookahead1();
-                match () {
-                    | _case if snoopy.peek(Token![yield]) => {
-                        if yield_ty.is_some() {
-                            return Err(input.error("already provided"));
-                        }
-                        let _: Token![yield] = input.parse().unwrap();
+struct Params {
+    yield_ty: Type,
+    resume: Option<(Type, Option<Pat>)>,
+    /// `boxed` or `boxed_send`: whether the fn directly returns a
+    /// `Pin<Box<dyn Generator>>` (and whether that `dyn` is `Send`).
+    boxed: Option<(Span, bool)>,
+    /// Whether the generator is meant to be polled as an `AsyncGenerator`.
+    asyncness: Option<Token![async]>,
+    /// Whether `yield_!` evaluates to a `Result<ResumeArg, Closed>`, so as to
+    /// support `GeneratorFn::close`.
+    closable: Option<Span>,
+    /// `throws(E)`: whether the resume arg (already wrapped in `resume` as a
+    /// `Result<ResumeArg, E>`) is to be `?`-unwrapped when initially bound.
+    throws: bool,
+    /// Whether the body is statically asserted to be `Send`.
+    send: Option<Span>,
+}
+impl Parse for Params {
+    fn parse (input: ParseStream<'_>)
+      -> Result<Params>
+    {
+        mod kw {
+            ::syn::custom_keyword!(resume);
+            ::syn::custom_keyword!(boxed);
+            ::syn::custom_keyword!(boxed_send);
+            ::syn::custom_keyword!(closable);
+            ::syn::custom_keyword!(throws);
+            ::syn::custom_keyword!(send);
+        }
+        let mut yield_ty: Option<Type> = None;
+        let mut resume: Option<(Type, Option<Pat>)> = None;
+        let mut boxed: Option<(Span, bool)> = None;
+        let mut asyncness: Option<Token![async]> = None;
+        let mut closable: Option<Span> = None;
+        let mut throws: Option<Type> = None;
+        let mut send: Option<Span> = None;
+        // (`gen!` stops at its `=>`)
+        while input.is_empty().not() && input.peek(Token![=>]).not() {
+            let snoopy = input.lookahead1();
+            match () {
+                | _case if snoopy.peek(Token![yield]) => {
+                    if yield_ty.is_some() {
+                        return Err(input.error("already provided"));
+                    }
+                    let _: Token![yield] = input.parse().unwrap();
+                    let parenthesized; parenthesized!(parenthesized in input);
+                    yield_ty.replace(parenthesized.parse()?);
+                    let _: Option<Token![,]> = parenthesized.parse()?;
+                },
+                | _case if snoopy.peek(kw::resume) => {
+                    if resume.is_some() {
+                        return Err(input.error("already provided"));
+                    }
+                    let _: kw::resume = input.parse().unwrap();
+                    let resume_ty: Type = {
                         let parenthesized; parenthesized!(parenthesized in input);
-                        yield_ty.replace(parenthesized.parse()?);
+                        let it = parenthesized.parse()?;
                         let _: Option<Token![,]> = parenthesized.parse()?;
-                    },
-                    | _case if snoopy.peek(kw::resume) => {
-                        if resume.is_some() {
-                            return Err(input.error("already provided"));
-                        }
-                        let _: kw::resume = input.parse().unwrap();
-                        let resume_ty: Type = {
-                            let parenthesized; parenthesized!(parenthesized in input);
-                            let it = parenthesized.parse()?;
-                            let _: Option<Token![,]> = parenthesized.parse()?;
-                            it
-                        };
-                        let mut resume_pat = None;
-                        if input.parse::<Option<Token![as]>>()?.is_some() {
-                            resume_pat.replace(input.parse()?);
-                        }
-                        resume.replace((resume_ty, resume_pat));
-                    },
-                    // Slightly improve the error message for extraneous
-                    // trailing stuff.
-                    | _case if yield_ty.is_some() && resume.is_some() => break,
-                    | _default => return Err(snoopy.error()),
-                }
-                let _: Option<Token![,]> = input.parse()?;
+                        it
+                    };
+                    let mut resume_pat = None;
+                    if input.parse::<Option<Token![as]>>()?.is_some() {
+                        resume_pat.replace(input.parse()?);
+                    }
+                    resume.replace((resume_ty, resume_pat));
+                },
+                | _case if snoopy.peek(kw::boxed) || snoopy.peek(kw::boxed_send) => {
+                    if boxed.is_some() {
+                        return Err(input.error("already provided"));
+                    }
+                    boxed.replace(if input.peek(kw::boxed) {
+                        (input.parse::<kw::boxed>().unwrap().span, false)
+                    } else {
+                        (input.parse::<kw::boxed_send>().unwrap().span, true)
+                    });
+                },
+                | _case if snoopy.peek(Token![async]) => {
+                    if asyncness.is_some() {
+                        return Err(input.error("already provided"));
+                    }
+                    asyncness.replace(input.parse().unwrap());
+                },
+                | _case if snoopy.peek(kw::closable) => {
+                    if closable.is_some() {
+                        return Err(input.error("already provided"));
+                    }
+                    closable.replace(input.parse::<kw::closable>().unwrap().span);
+                },
+                | _case if snoopy.peek(kw::throws) => {
+                    if throws.is_some() {
+                        return Err(input.error("already provided"));
+                    }
+                    let _: kw::throws = input.parse().unwrap();
+                    let parenthesized; parenthesized!(parenthesized in input);
+                    throws.replace(parenthesized.parse()?);
+                    let _: Option<Token![,]> = parenthesized.parse()?;
+                },
+                | _case if snoopy.peek(kw::send) => {
+                    if send.is_some() {
+                        return Err(input.error("already provided"));
+                    }
+                    send.replace(input.parse::<kw::send>().unwrap().span);
+                },
+                // Slightly improve the error message for extraneous
+                // trailing stuff.
+                | _case if yield_ty.is_some() && resume.is_some() => break,
+                | _default => return Err(snoopy.error()),
             }
-            let yield_ty = if let Some(it) = yield_ty { it } else {
-                return Err(input.error("missing `yield(<yield type>)`"));
-            };
-            Ok(Self { yield_ty, resume })
+            let _: Option<Token![,]> = input.parse()?;
+        }
+        let yield_ty = if let Some(it) = yield_ty { it } else {
+            return Err(input.error("missing `yield(<yield type>)`"));
+        };
+        if let (Some(asyncness), Some(_)) = (asyncness, &resume) {
+            return Err(Error::new_spanned(
+                asyncness,
+                "`async` generators do not support `resume(…)` args",
+            ));
         }
+        if let (Some(asyncness), Some(_)) = (asyncness, closable) {
+            return Err(Error::new_spanned(
+                asyncness,
+                "`async` generators do not support being `closable`",
+            ));
+        }
+        if let (Some(asyncness), Some(_)) = (asyncness, &throws) {
+            return Err(Error::new_spanned(
+                asyncness,
+                "`async` generators do not support `throws(…)`",
+            ));
+        }
+        // The generator is resumed with `Result<ResumeArg, E>`s.
+        if let Some(ref ErrTy) = throws {
+            let (ResumeArg, resume_arg_pat) =
+                resume.take().unwrap_or_else(|| (parse_quote!( () ), None))
+            ;
+            resume.replace((
+                parse_quote!( ::core::result::Result<#ResumeArg, #ErrTy> ),
+                resume_arg_pat,
+            ));
+        }
+        let throws = throws.is_some();
+        // `boxed, send` is `boxed_send`.
+        if let (Some(_), Some((_, ref mut dyn_send))) = (send, &mut boxed) {
+            *dyn_send = true;
+        }
+        Ok(Self { yield_ty, resume, boxed, asyncness, closable, throws, send })
     }
+}
 
+fn generator_impl (
+    params: TokenStream2,
+    input: TokenStream2,
+) -> Result<TokenStream2>
+{
+    let params: Params = parse2(params)?;
+    let mut fun: ItemFn = match parse2(input.clone()) {
+        | Ok(it) => it,
+        | Err(err) => return match parse2::<ExprClosure>(input) {
+            | Ok(closure) => {
+                let each_input =
+                    closure
+                        .inputs
+                        .into_iter()
+                        .map(|pat| match pat {
+                            | Pat::Type(PatType { pat, ty, .. }) => (*pat, *ty),
+                            | _ => (pat, parse_quote!( _ )),
+                        })
+                        .collect()
+                ;
+                let Ret = match closure.output {
+                    | ReturnType::Type(_, ty) => Some(*ty),
+                    | ReturnType::Default => None,
+                };
+                let body = &closure.body;
+                closure_impl(
+                    params,
+                    closure.capture,
+                    each_input,
+                    Ret,
+                    parse_quote!({ #body }),
+                )
+            },
+            | Err(_) => Err(err),
+        },
+    };
+    if params.boxed.is_some() {
+        return boxed_impl(params, fun);
+    }
+    diagnostics::check_body(&fun.block, params.asyncness.is_some())?;
     let Params {
-        yield_ty: YieldTy @ _,
+        yield_ty: mut YieldTy @ _,
         resume,
-    } = parse2(params)?;
-    let mut fun: ItemFn = parse2(input)?;
-    let ItemFn {
-        ref mut block,
-        ref mut sig,
-        ..
-    } = fun;
+        boxed: _,
+        asyncness,
+        closable,
+        throws,
+        send,
+    } = params;
+    // `async` needs no dedicated lowering: the `GeneratorFn` instances are
+    // `AsyncGenerator`s already; it just lets the body `.await` (see
+    // `diagnostics`), and keeps it from being lowered to a native coroutine.
+    let modes = Modes {
+        is_async: asyncness.is_some(),
+        closable: closable.is_some(),
+        throws,
+    };
 
     let __yield_slot__ = Ident::new(
         "__yield_slot__",
         ::proc_macro::Span::mixed_site().into(),
     );
+    let mut ResumeArg = match resume {
+        | Some((ref ResumeArg @ _, _)) => ResumeArg.clone(),
+        | None => parse_quote!( () ),
+    };
+
+    // A `self` receiver requires splitting the method in two: a hidden
+    // associated function taking the receiver within its packed args, and
+    // the method itself, returning that function along with its args.
+    let method = if let Some(receiver) = fun.sig.receiver() {
+        let __self__ = Ident::new(
+            "__self__",
+            Span::from(::proc_macro::Span::mixed_site())
+                .located_at(receiver.span()),
+        );
+        let mut method = fun.clone();
+        let __self = Lifetime::new("'__self", Span::call_site());
+        utils::FillElidedLifetimes(&__self).visit_signature_mut(&mut method.sig);
+        utils::FillElidedLifetimes(&__self).visit_type_mut(&mut YieldTy);
+        utils::FillElidedLifetimes(&__self).visit_type_mut(&mut ResumeArg);
+        method.sig.generics.params.insert(0, parse_quote!( #__self ));
+        fun.sig = method.sig.clone();
+        fun.sig.ident = format_ident!("__next_gen_{}__", method.sig.ident);
+        fun.attrs.retain(|attr| attr.path.is_ident("cfg"));
+        fun.attrs.push(parse_quote!( #[doc(hidden)] ));
+        fun.vis = Visibility::Inherited;
+        utils::ReplaceSelf(&__self__).visit_block_mut(&mut fun.block);
+        // The receiver becomes the first element of the packed args.
+        let receiver = fun.sig.inputs.iter_mut().next().unwrap();
+        *receiver = match *receiver {
+            | FnArg::Receiver(Receiver {
+                ref attrs, ref reference, ref mutability, ..
+            }) => {
+                let ty: Type = match *reference {
+                    | Some((_, ref lifetime)) => parse_quote!(
+                        & #lifetime #mutability Self
+                    ),
+                    | None => parse_quote!( Self ),
+                };
+                let pat = if reference.is_some() { None } else { *mutability };
+                parse_quote!( #(#attrs)* #pat #__self__: #ty )
+            },
+            | FnArg::Typed(ref pat_ty) => {
+                let mut pat_ty = pat_ty.clone();
+                if let Pat::Ident(ref mut pat) = *pat_ty.pat {
+                    pat.ident = __self__.clone();
+                }
+                FnArg::Typed(pat_ty)
+            },
+        };
+        Some(method)
+    } else {
+        None
+    };
+
+    let ItemFn {
+        ref mut block,
+        ref mut sig,
+        ..
+    } = fun;
 
     // Handle the signature
+    let native = modes.native();
     let resume_arg_pat = {
-        sig.asyncness = parse_quote!( async );
-
-        if let Some(receiver) = sig.receiver() {
-            return Err(Error::new_spanned(
-                receiver,
-                "`self` receivers are not supported yet",
-            ));
+        if native.not() {
+            sig.asyncness = parse_quote!( async );
         }
 
-        let (/* mut */ each_pat, /* mut */ EachTy @ _): (Vec<_>, Vec<_>) =
+        let (/* mut */ each_pat, mut EachTy @ _): (Vec<_>, Vec<_>) =
             ::core::mem::take(&mut sig.inputs)
                 .into_iter()
                 .map(|fn_arg| match fn_arg {
@@ -158,41 +389,672 @@ fn generator_impl (
                 })
                 .unzip()
         ;
-        let (resume_arg_pat, ResumeArg @ _) = match resume {
-            | Some((ResumeArg @ _, initial_resume_arg_pat)) => (
-                initial_resume_arg_pat.unwrap_or_else(|| parse_quote!( _ )),
-                ResumeArg.into_token_stream(),
-            ),
-            | None => (
-                parse_quote!( _ ),
-                quote!(),
-            ),
+        // A native body is returned as an `impl Future` (rather than by an
+        // `async fn`), which only captures the lifetimes it names: so name the
+        // elided ones, and the one of the `YieldSlot` (which the body holds),
+        // much like `boxed` does.
+        let yield_slot_lifetime = match method {
+            | Some(_) => quote!( '__self ),
+            | None if native => {
+                sig.generics.params.insert(0, parse_quote!( '__yield_slot ));
+                quote!( '__yield_slot )
+            },
+            | None => quote!( '_ ),
         };
+        if native && method.is_none() {
+            let __gen = Lifetime::new("'__gen", Span::call_site());
+            let elided = quote!( #(#EachTy)* #YieldTy #ResumeArg ).to_string();
+            EachTy.iter_mut().for_each(|ty| {
+                utils::FillElidedLifetimes(&__gen).visit_type_mut(ty);
+            });
+            utils::FillElidedLifetimes(&__gen).visit_type_mut(&mut YieldTy);
+            utils::FillElidedLifetimes(&__gen).visit_type_mut(&mut ResumeArg);
+            if quote!( #(#EachTy)* #YieldTy #ResumeArg ).to_string() != elided {
+                sig.generics.params.insert(0, parse_quote!( #__gen ));
+            }
+        }
         sig.inputs = parse_quote!(
-            #__yield_slot__: ::next_gen::__::__Internals_YieldSlot_DoNotUse__<'_, #YieldTy, #ResumeArg>,
+            #__yield_slot__: ::next_gen::__::__Internals_YieldSlot_DoNotUse__<
+                #yield_slot_lifetime,
+                #YieldTy,
+                #ResumeArg,
+            >,
             (
                 #(#each_pat ,)*
             ): (
                 #(#EachTy ,)*
             ),
         );
-        resume_arg_pat
+        match resume {
+            | Some((_, Some(initial_resume_arg_pat))) => initial_resume_arg_pat,
+            | _ => parse_quote!( _ ),
+        }
     };
 
     // Update block to generate `yield_!` macro.
-    {
-        *block = parse_quote!({
-            macro_rules! yield_ {(
-                $value:expr $(,)?
-            ) => (
-                #__yield_slot__.__put($value).await
-            )}
-
-            let #resume_arg_pat = #__yield_slot__.__take_initial_arg();
+    let Ret = match sig.output {
+        | ReturnType::Type(_, ref Ret) => Some((**Ret).clone()),
+        | ReturnType::Default => None,
+    };
+    **block = lower_body(
+        &__yield_slot__,
+        &ResumeArg,
+        &resume_arg_pat,
+        Ret.as_ref(),
+        modes,
+        NativeCaptures { capture: parse_quote!( move ), unpack: quote!() },
+        block,
+    );
+    // `send`: the body is moved into an `async` block asserted to be `Send`,
+    // so that the offending captured locals, if any, get reported here.
+    if let Some(send_span) = send {
+        let ret_hint = Ret.as_ref().map(ret_hint);
+        let assert_send = quote_spanned!(send_span=>
+            ::next_gen::__::assert_send
+        );
+        **block = if native {
+            parse_quote!({
+                #assert_send(#block)
+            })
+        } else {
+            parse_quote!({
+                #assert_send(async move {
+                    #ret_hint
+                    #block
+                }).await
+            })
+        };
+    }
+    if native {
+        let Ret = Ret.unwrap_or_else(|| parse_quote!( () ));
+        let each_lifetime = sig.generics.lifetimes().map(|it| &it.lifetime);
+        let Self_ = method.as_ref().map(|_| quote!(
+            ::core::marker::PhantomData<Self>,
+        ));
+        sig.output = parse_quote!(
+            -> impl ::core::future::Future<Output = #Ret>
+                + ::next_gen::__::Captures<(#(&#each_lifetime (),)* #Self_)>
+        );
+    }
 
-            #block
+    if let Some(mut method) = method {
+        let Signature {
+            ref mut inputs,
+            ref mut output,
+            ref mut generics,
+            ..
+        } = method.sig;
+        let each_arg = (0 .. inputs.len() - 1).map(|i| format_ident!("__arg_{}", i));
+        inputs.iter_mut().skip(1).zip(each_arg.clone()).for_each(|(fn_arg, arg)| {
+            if let FnArg::Typed(PatType { ref mut pat, .. }) = *fn_arg {
+                *pat = parse_quote!( #arg );
+            }
         });
+        // Type parameters can only be inferred through the args and the yield
+        // type, so turbofish them when possible (_i.e._, no `impl` args).
+        let turbofish = if inputs.iter().any(|fn_arg| matches!(
+            *fn_arg,
+            FnArg::Typed(PatType { ref ty, .. }) if utils::has_impl_trait(ty)
+        ))
+        {
+            None
+        } else {
+            let each_param = generics.params.iter().filter_map(|param| match *param {
+                | GenericParam::Type(TypeParam { ref ident, .. }) => Some(ident),
+                | GenericParam::Const(ConstParam { ref ident, .. }) => Some(ident),
+                | GenericParam::Lifetime(_) => None,
+            });
+            Some(quote!( ::<#(#each_param),*> ))
+        };
+        let each_lifetime =
+            generics
+                .lifetimes()
+                .map(|it| it.lifetime.clone())
+                .filter(|it| it.ident != "__self")
+                .collect::<Vec<_>>()
+        ;
+        generics.make_where_clause().predicates.extend(
+            each_lifetime.iter().map(|it| -> WherePredicate {
+                parse_quote!( #it : '__self )
+            })
+        );
+        generics.make_where_clause().predicates.extend(
+            [&YieldTy, &ResumeArg].iter().map(|ty| -> WherePredicate {
+                parse_quote!( #ty : '__self )
+            })
+        );
+        // The receiver is just forwarded, so `mut self` is not needed here.
+        match inputs.iter_mut().next() {
+            | Some(&mut FnArg::Receiver(ref mut receiver)) => {
+                if receiver.reference.is_none() {
+                    receiver.mutability = None;
+                }
+            },
+            | Some(&mut FnArg::Typed(PatType { ref mut pat, .. })) => {
+                if let Pat::Ident(ref mut pat) = **pat {
+                    pat.mutability = None;
+                }
+            },
+            | None => unreachable!(),
+        }
+        let Ret = match ::core::mem::replace(output, ReturnType::Default) {
+            | ReturnType::Type(_, ty) => *ty,
+            | ReturnType::Default => parse_quote!( () ),
+        };
+        // (The `Future` is opaque, so `send`ness must be stated explicitly.)
+        let future_send = send.map(|_| quote!(
+            Future = impl ::core::future::Future<Output = #Ret>
+                        + ::core::marker::Send
+                        + ::next_gen::__::Captures<&'__self Self>
+            ,
+        ));
+        *output = parse_quote!(
+            -> impl ::next_gen::generator_fn::GeneratorMethodCall<
+                '__self,
+                #YieldTy,
+                #ResumeArg,
+                Return = #Ret,
+                #future_send
+            >
+            // Capture the lifetime parameters of the `impl` block, if any.
+            + ::next_gen::__::Captures<Self>
+        );
+        let packed_fn = &fun.sig.ident;
+        method.attrs.push(parse_quote!( #[inline] ));
+        *method.block = parse_quote!({
+            (
+                Self::#packed_fn #turbofish,
+                (self, #(#each_arg ,)*),
+            )
+        });
+        return Ok(quote!( #fun #method ));
     }
 
     Ok(fun.into_token_stream())
 }
+
+/// `#[generator(yield(T), boxed)]` keeps the signature of the function, but
+/// for its return type, which becomes a `Pin<Box<dyn Generator>>`. The body is
+/// thus lowered as a `move` generator closure, which is then `.call_boxed()`.
+fn boxed_impl (
+    mut params: Params,
+    mut fun: ItemFn,
+) -> Result<TokenStream2>
+{
+    let (boxed_span, send) = params.boxed.take().unwrap();
+    if let Some(asyncness) = fun.sig.asyncness {
+        return Err(Error::new_spanned(asyncness, "`async fn`s are not supported"));
+    }
+    let __gen = Lifetime::new("'__gen", Span::call_site());
+    let sig = &mut fun.sig;
+    utils::FillElidedLifetimes(&__gen).visit_signature_mut(sig);
+    utils::FillElidedLifetimes(&__gen).visit_type_mut(&mut params.yield_ty);
+    if let Some((ref mut ResumeArg, _)) = params.resume {
+        utils::FillElidedLifetimes(&__gen).visit_type_mut(ResumeArg);
+    }
+    // Everything the generator captures must outlive the `dyn` lifetime.
+    // Since an extra bound makes `'__gen` early-bound (which would prevent
+    // implementing a trait method with this), only add the required ones.
+    let mut each_bound: Vec<WherePredicate> =
+        sig .generics
+            .params
+            .iter()
+            .filter_map(|param| Some(match *param {
+                | GenericParam::Type(TypeParam { ref ident, .. }) => {
+                    parse_quote!( #ident : #__gen )
+                },
+                | GenericParam::Lifetime(LifetimeDef { ref lifetime, .. }) => {
+                    parse_quote!( #lifetime : #__gen )
+                },
+                | GenericParam::Const(_) => return None,
+            }))
+            .collect()
+    ;
+    match sig.receiver() {
+        | Some(&FnArg::Receiver(Receiver { reference: Some(_), .. })) => {},
+        | Some(_) => each_bound.push(parse_quote!( Self : #__gen )),
+        | None => {},
+    }
+    sig.inputs.iter_mut().for_each(|fn_arg| {
+        if let FnArg::Typed(PatType { ref mut ty, .. }) = *fn_arg {
+            utils::BoundImplTraits(&__gen).visit_type_mut(ty);
+        }
+    });
+    sig.generics.params.insert(0, parse_quote!( #__gen ));
+    if each_bound.is_empty().not() {
+        sig.generics.make_where_clause().predicates.extend(each_bound);
+    }
+    let Ret = match mem::replace(&mut sig.output, ReturnType::Default) {
+        | ReturnType::Type(_, ty) => *ty,
+        | ReturnType::Default => parse_quote!( () ),
+    };
+    let YieldTy = &params.yield_ty;
+    let ResumeArg = match params.resume {
+        | Some((ref ResumeArg @ _, _)) => ResumeArg.clone(),
+        | None => parse_quote!( () ),
+    };
+    let Send = if send {
+        Some(quote_spanned!(boxed_span=> + ::core::marker::Send ))
+    } else {
+        None
+    };
+    let Generator = if params.asyncness.is_some() {
+        quote!(
+            ::next_gen::async_generator::AsyncGenerator<
+                Yield = #YieldTy,
+                Return = #Ret,
+            >
+        )
+    } else {
+        quote!(
+            ::next_gen::generator::Generator<
+                #ResumeArg,
+                Yield = #YieldTy,
+                Return = #Ret,
+            >
+        )
+    };
+    sig.output = parse_quote!(
+        -> ::core::pin::Pin<::next_gen::__::alloc::boxed::Box<
+            dyn #__gen + #Generator #Send
+        >>
+    );
+    let generator_closure = closure_impl(
+        params,
+        parse_quote!( move ),
+        vec![],
+        Some(Ret),
+        (*fun.block).clone(),
+    )?;
+    *fun.block = parse_quote!({
+        ::next_gen::generator_fn::CallBoxed::call_boxed(#generator_closure, ())
+    });
+    Ok(fun.into_token_stream())
+}
+
+/// `#[coroutine(Y, …)] fn f (…, pat @ yield_: R)` is sugar for
+/// `#[generator(yield(Y), resume(R) as pat, …)] fn f (…)`.
+fn coroutine_impl (
+    params: TokenStream2,
+    input: TokenStream2,
+) -> Result<TokenStream2>
+{
+    struct CoroutineParams {
+        yield_ty: Type,
+        rest: Option<(Token![,], TokenStream2)>,
+    }
+    impl Parse for CoroutineParams {
+        fn parse (input: ParseStream<'_>)
+          -> Result<CoroutineParams>
+        {
+            Ok(CoroutineParams {
+                yield_ty: input.parse()?,
+                rest: if input.is_empty() { None } else {
+                    Some((input.parse()?, input.parse()?))
+                },
+            })
+        }
+    }
+
+    let CoroutineParams { yield_ty: YieldTy @ _, rest } = parse2(params)?;
+    let mut fun: ItemFn = parse2(input)?;
+    let mut resume: Option<(Pat, Type)> = None;
+    let mut errors = vec![];
+    fun.sig.inputs =
+        mem::take(&mut fun.sig.inputs)
+            .into_iter()
+            .filter_map(|fn_arg| {
+                let (pat, ty) = match fn_arg {
+                    | FnArg::Typed(PatType { ref pat, ref ty, .. }) => (pat, ty),
+                    | FnArg::Receiver(_) => return Some(fn_arg),
+                };
+                let is_yield_ = |pat: &Pat| matches!(
+                    *pat,
+                    Pat::Ident(PatIdent { ref ident, subpat: None, .. })
+                    if ident == "yield_"
+                );
+                let resume_arg_pat: Pat = match **pat {
+                    // `yield_: R`
+                    | ref pat if is_yield_(pat) => parse_quote!( _ ),
+                    // `pat @ yield_: R`
+                    | Pat::Ident(PatIdent { subpat: Some((_, ref subpat)), .. })
+                        if is_yield_(subpat)
+                    => {
+                        let mut pat = pat.clone();
+                        if let Pat::Ident(ref mut pat) = *pat {
+                            pat.subpat = None;
+                        }
+                        *pat
+                    },
+                    | _ => return Some(fn_arg),
+                };
+                if resume.is_some() {
+                    errors.push(Error::new_spanned(pat, "duplicate `yield_` parameter"));
+                }
+                resume = Some((resume_arg_pat, (**ty).clone()));
+                None
+            })
+            .collect()
+    ;
+    if let Some(mut err) = errors.pop() {
+        errors.into_iter().for_each(|it| err.combine(it));
+        return Err(err);
+    }
+    let resume = resume.map(|(pat, ResumeArg)| quote!(
+        , resume(#ResumeArg) as #pat
+    ));
+    let rest = rest.map(|(comma, rest)| quote!( #comma #rest ));
+    generator_impl(
+        quote!( yield(#YieldTy) #resume #rest ),
+        fun.into_token_stream(),
+    )
+}
+
+fn gen_impl (
+    input: TokenStream2,
+) -> Result<TokenStream2>
+{
+    struct Gen {
+        params: Params,
+        capture: Option<Token![move]>,
+        block: Block,
+    }
+    impl Parse for Gen {
+        fn parse (input: ParseStream<'_>)
+          -> Result<Gen>
+        {
+            let params = input.parse()?;
+            let _: Token![=>] = input.parse()?;
+            Ok(Gen {
+                params,
+                capture: input.parse()?,
+                block: input.parse()?,
+            })
+        }
+    }
+
+    let Gen { params, capture, block } = parse2(input)?;
+    closure_impl(params, capture, vec![], None, block)
+}
+
+/// Generators in expression position (`gen!` and `#[generator]` closures)
+/// are closures returning an `async` block, the args of the closure being
+/// packed, as with `#[generator]` functions.
+fn closure_impl (
+    Params {
+        yield_ty: YieldTy @ _,
+        resume,
+        boxed,
+        asyncness,
+        closable,
+        throws,
+        send,
+    }: Params,
+    capture: Option<Token![move]>,
+    each_input: Vec<(Pat, Type)>,
+    Ret: Option<Type>,
+    block: Block,
+) -> Result<TokenStream2>
+{
+    if let Some((span, _)) = boxed {
+        return Err(Error::new(span, "only supported on functions"));
+    }
+    diagnostics::check_body(&block, asyncness.is_some())?;
+    let __yield_slot__ = Ident::new(
+        "__yield_slot__",
+        ::proc_macro::Span::mixed_site().into(),
+    );
+    let __captures__ = Ident::new(
+        "__captures__",
+        ::proc_macro::Span::mixed_site().into(),
+    );
+    let (ResumeArg, resume_arg_pat) = match resume {
+        | Some((ResumeArg @ _, resume_arg_pat)) => (
+            ResumeArg,
+            resume_arg_pat.unwrap_or_else(|| parse_quote!( _ )),
+        ),
+        | None => (parse_quote!( () ), parse_quote!( _ )),
+    };
+    // Unless told otherwise, the return type of an `async` block is inferred
+    // from its first `return`, so make the first one be of the right type.
+    let ret_hint = Ret.as_ref().map(ret_hint);
+    let (each_pat, EachTy @ _): (Vec<_>, Vec<_>) = each_input.into_iter().unzip();
+    let modes = Modes {
+        is_async: asyncness.is_some(),
+        closable: closable.is_some(),
+        throws,
+    };
+    let block = lower_body(
+        &__yield_slot__,
+        &ResumeArg,
+        &resume_arg_pat,
+        Ret.as_ref(),
+        modes,
+        NativeCaptures {
+            capture,
+            unpack: quote!(
+                let (#(#each_pat ,)*) = #__captures__.into_inner();
+            ),
+        },
+        &block,
+    );
+    let Ret = Ret.unwrap_or_else(|| parse_quote!( _ ));
+    let assert_send = send.map(|send_span| quote_spanned!(send_span=>
+        ::next_gen::__::assert_send
+    ));
+    if modes.native() {
+        return Ok(quote!(
+            ::next_gen::__::generator_closure::<#YieldTy, #ResumeArg, _, #Ret, _, _>(
+                #capture |#__yield_slot__, #__captures__: (#(#EachTy ,)*)| {
+                    // Make sure the coroutine captures these by value, while
+                    // still capturing the environment by reference (unless
+                    // `move`).
+                    let #__captures__ = ::next_gen::__::Moved(#__captures__);
+                    #assert_send(#block)
+                }
+            )
+        ));
+    }
+    Ok(quote!(
+        ::next_gen::__::generator_closure::<#YieldTy, #ResumeArg, _, #Ret, _, _>(
+            #capture |#__yield_slot__, #__captures__: (#(#EachTy ,)*)| {
+                // Make sure the `async` block captures these by value, while
+                // still capturing the environment by reference (unless `move`).
+                let #__captures__ = ::next_gen::__::Moved((
+                    #__yield_slot__,
+                    #__captures__,
+                ));
+                ::next_gen::__::ClosureFuture(#assert_send(async #capture {
+                    let (#__yield_slot__, (#(#each_pat ,)*)) =
+                        #__captures__.into_inner()
+                    ;
+                    #ret_hint
+                    #block
+                }))
+            }
+        )
+    ))
+}
+
+/// Unless told otherwise, the return type of an `async` block (or of a
+/// closure) is inferred from its first `return`, so make the first one be of
+/// the right type.
+fn ret_hint (Ret: &'_ Type)
+  -> TokenStream2
+{
+    let ret = quote_spanned!(Ret.span()=>
+        return ::next_gen::__::unreachable::<#Ret>()
+    );
+    quote!(
+        #[allow(unreachable_code)]
+        if false {
+            #ret;
+        }
+    )
+}
+
+/// The parameters of `#[generator]` affecting the lowering of its body.
+#[derive(Clone, Copy)]
+struct Modes {
+    is_async: bool,
+    closable: bool,
+    throws: bool,
+}
+
+impl Modes {
+    /// Whether the body is lowered to a native coroutine (see [`lower_body`]).
+    fn native (self: Self)
+      -> bool
+    {
+        cfg!(feature = "native-coroutines")
+        && self.is_async.not()
+        && self.closable.not()
+        && self.throws.not()
+    }
+}
+
+/// How the native coroutine of a body captures its environment: by value, or
+/// by reference (unless `move`), its `unpack` statements then unpacking the
+/// packed args of a closure.
+struct NativeCaptures {
+    capture: Option<Token![move]>,
+    unpack: TokenStream2,
+}
+
+/// Defines the `yield_!` (and `yield_from!`) macros and binds the initial
+/// resume argument, before running the given body.
+///
+/// With the `native-coroutines` feature, the body (of a generator with none of
+/// these `Modes`) rather evaluates to a native coroutine, which the generator
+/// then resumes directly, each `yield_!` becoming a native `yield`.
+///
+/// In `closable` mode, `yield_!` evaluates to a `Result<ResumeArg, Closed>`,
+/// whereas `yield_from!` keeps delegating through plain yield points.
+///
+/// In `throws(E)` mode, the initial resume arg is `?`-unwrapped.
+fn lower_body (
+    __yield_slot__: &'_ Ident,
+    ResumeArg: &'_ Type,
+    resume_arg_pat: &'_ Pat,
+    Ret: Option<&'_ Type>,
+    modes: Modes,
+    NativeCaptures { capture, unpack }: NativeCaptures,
+    block: &'_ Block,
+) -> Block
+{
+    let Modes { closable, throws, .. } = modes;
+    let native = modes.native();
+    let try_ = if throws { Some(quote!( ? )) } else { None };
+    let stmts = &block.stmts;
+    // The `__put()` future is bound in its own statement, so that the
+    // `&YieldSlot` autoref is not held across the `.await` (older compilers
+    // would otherwise require `YieldSlot : Sync` for the future to be `Send`).
+    let put = if native {
+        quote!( ::next_gen::__::__native_yield__!($value) )
+    } else {
+        quote!({ let put = #__yield_slot__.__put($value); put }.await)
+    };
+    let yield_ = if closable {
+        quote!({
+            let put = #__yield_slot__.__put_closable($value);
+            put
+        }.await)
+    } else {
+        put.clone()
+    };
+    let body = if native {
+        let ret_hint = Ret.map(ret_hint);
+        quote!(
+            ::next_gen::__::__native_coroutine__!(
+                #__yield_slot__, #ResumeArg, [#capture] |#resume_arg_pat| {
+                    #unpack
+                    #ret_hint
+                    #(#stmts)*
+                }
+            )
+        )
+    } else {
+        quote!(
+            let #resume_arg_pat = #__yield_slot__.__take_initial_arg() #try_;
+
+            // (Not `#block`, lest `unused_braces` fire on single-expression bodies.)
+            #(#stmts)*
+        )
+    };
+    // The macros get crate-internal names, which the prelude's `yield_!` and
+    // `yield_from!` forward to: this way, `use yield_ as …;` within the body
+    // resolves to the very same item whether the prelude is glob-imported or
+    // not (older compilers would otherwise deem it ambiguous).
+    parse_quote!({
+        #[allow(unused_imports)]
+        use ::next_gen::prelude::{yield_, yield_from};
+
+        macro_rules! __next_gen_yield__ {(
+            $value:expr $(,)?
+        ) => (
+            #yield_
+        )}
+
+        #[allow(unused_macros)]
+        macro_rules! __next_gen_put__ {(
+            $value:expr
+        ) => (
+            #put
+        )}
+
+        #[allow(unused_macros)]
+        macro_rules! __next_gen_yield_from__ {
+            (@drive $generator:ident, ($($first_resume_arg:expr)?)) => ({
+                // (Without a first resume argument, the sub-generator has to
+                // be `()`-resumed.)
+                let mut resume_arg = ($($first_resume_arg)?);
+                loop {
+                    match
+                        ::next_gen::generator::Generator::resume(
+                            $generator.as_mut(),
+                            resume_arg,
+                        )
+                    {
+                        | ::next_gen::generator::GeneratorState::Yielded(it) => {
+                            resume_arg = __next_gen_put__!(it);
+                        },
+                        | ::next_gen::generator::GeneratorState::Returned(it) => {
+                            break it;
+                        },
+                    }
+                }
+            });
+
+            (
+                $generator:tt ( $($args:expr),* $(,)? )
+                $(, $first_resume_arg:expr)? $(,)?
+            ) => ({
+                ::next_gen::mk_gen!(
+                    let mut generator = $generator($($args),*)
+                );
+                __next_gen_yield_from__!(@drive generator, (
+                    $($first_resume_arg)?
+                ))
+            });
+
+            (
+                $generator:expr $(, $first_resume_arg:expr)? $(,)?
+            ) => (
+                match
+                    <_ as ::next_gen::generator::IntoGenerator<_>>
+                        ::into_generator($generator)
+                {
+                    generator => {
+                        ::next_gen::stack_pinned!(mut generator);
+                        __next_gen_yield_from__!(@drive generator, (
+                            $($first_resume_arg)?
+                        ))
+                    },
+                }
+            );
+
+        }
+
+        #body
+    })
+}
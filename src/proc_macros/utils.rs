@@ -47,3 +47,153 @@ fn pretty_print_tokenstream (
         println!("{}", code);
     }
 }
+
+/// Names the elided lifetimes of a signature after the given one, much like
+/// lifetime elision in return position would do with a `self` receiver.
+pub(in crate)
+struct FillElidedLifetimes<'lt> (
+    pub(in crate) &'lt Lifetime,
+);
+
+impl VisitMut for FillElidedLifetimes<'_> {
+    fn visit_receiver_mut (self: &'_ mut Self, receiver: &'_ mut Receiver)
+    {
+        if let Some((_, ref mut lifetime @ None)) = receiver.reference {
+            *lifetime = Some(self.0.clone());
+        }
+        visit_mut::visit_receiver_mut(self, receiver);
+    }
+
+    fn visit_type_reference_mut (self: &'_ mut Self, ty: &'_ mut TypeReference)
+    {
+        if ty.lifetime.is_none() {
+            ty.lifetime = Some(self.0.clone());
+        }
+        visit_mut::visit_type_reference_mut(self, ty);
+    }
+
+    fn visit_lifetime_mut (self: &'_ mut Self, lifetime: &'_ mut Lifetime)
+    {
+        if lifetime.ident == "_" {
+            *lifetime = self.0.clone();
+        }
+    }
+
+    // Elided lifetimes within these introduce their own higher-order ones.
+    fn visit_type_bare_fn_mut (self: &'_ mut Self, _: &'_ mut TypeBareFn)
+    {}
+
+    fn visit_parenthesized_generic_arguments_mut (
+        self: &'_ mut Self,
+        _: &'_ mut ParenthesizedGenericArguments,
+    )
+    {}
+
+    // Do not recurse into the body.
+    fn visit_block_mut (self: &'_ mut Self, _: &'_ mut Block)
+    {}
+}
+
+/// Adds the given lifetime as a bound to every `impl Trait` type.
+pub(in crate)
+struct BoundImplTraits<'lt> (
+    pub(in crate) &'lt Lifetime,
+);
+
+impl VisitMut for BoundImplTraits<'_> {
+    fn visit_type_impl_trait_mut (self: &'_ mut Self, ty: &'_ mut TypeImplTrait)
+    {
+        visit_mut::visit_type_impl_trait_mut(self, ty);
+        ty.bounds.push(TypeParamBound::Lifetime(self.0.clone()));
+    }
+}
+
+/// Whether a type contains some `impl Trait` (which prevents turbofishing).
+pub(in crate)
+fn has_impl_trait (ty: &'_ Type)
+  -> bool
+{
+    use ::syn::visit::{self, Visit};
+
+    struct HasImplTrait(bool);
+    impl Visit<'_> for HasImplTrait {
+        fn visit_type_impl_trait (self: &'_ mut Self, _: &'_ TypeImplTrait)
+        {
+            self.0 = true;
+        }
+    }
+    let mut visitor = HasImplTrait(false);
+    visitor.visit_type(ty);
+    visitor.0
+}
+
+/// Replaces the `self` values (not the `self::` paths) of a method body with
+/// the given ident, but for those within nested items, which have their own.
+///
+/// (Closures and `async` blocks, on the other hand, do capture the `self` of
+/// the method, and are thus replaced within.)
+pub(in crate)
+struct ReplaceSelf<'this> (
+    pub(in crate) &'this Ident,
+);
+
+impl VisitMut for ReplaceSelf<'_> {
+    fn visit_expr_path_mut (self: &'_ mut Self, expr: &'_ mut ExprPath)
+    {
+        if expr.qself.is_none() && expr.path.is_ident("self") {
+            let ident = &mut expr.path.segments[0].ident;
+            let mut this = self.0.clone();
+            this.set_span(this.span().located_at(ident.span()));
+            *ident = this;
+        }
+        visit_mut::visit_expr_path_mut(self, expr);
+    }
+
+    // Best effort: the `self` tokens within macro invocations are replaced
+    // as well, since most macros take expressions.
+    fn visit_macro_mut (self: &'_ mut Self, mac: &'_ mut Macro)
+    {
+        mac.tokens = replace_self_tokens(mem::take(&mut mac.tokens), self.0);
+    }
+
+    // Nested items have their own `self`, if any.
+    fn visit_item_mut (self: &'_ mut Self, _: &'_ mut Item)
+    {}
+}
+
+/// Replaces the `self` value tokens (not the `self::` paths) with `this`.
+fn replace_self_tokens (
+    tokens: TokenStream2,
+    this: &'_ Ident,
+) -> TokenStream2
+{
+    let mut tokens = tokens.into_iter().peekable();
+    let mut ret = TokenStream2::new();
+    while let Some(tt) = tokens.next() {
+        ret.extend(Some(match tt {
+            | TT::Ident(ident) if ident == "self" => {
+                let is_path = matches!(
+                    tokens.peek(),
+                    Some(TT::Punct(p)) if p.as_char() == ':'
+                );
+                if is_path {
+                    TT::Ident(ident)
+                } else {
+                    let mut this = this.clone();
+                    this.set_span(this.span().located_at(ident.span()));
+                    TT::Ident(this)
+                }
+            },
+            | TT::Group(group) => {
+                let mut new = ::proc_macro2::Group::new(
+                    group.delimiter(),
+                    replace_self_tokens(group.stream(), this),
+                );
+                new.set_span(group.span());
+                TT::Group(new)
+            },
+            | tt => tt,
+        }));
+    }
+    ret
+}
@@ -75,6 +75,37 @@ macro_rules! stack_pinned {
 /// assert_eq!(iterator.take(10_000).count(), 10_000);
 /// ```
 ///
+/// ## Method-call syntax
+///
+/// When the generator is a [`#[generator]`][`macro@crate::generator`]-annotated
+/// method with a `self` receiver, method-call syntax can be used as well:
+///
+/// > `mk_gen!(let $(mut)? <varname> = $(box)? <receiver>.<method> (<args>));`
+///
+/// ```rust
+/// use ::next_gen::prelude::*;
+///
+/// struct Countdown {
+///     from: u8,
+/// }
+///
+/// impl Countdown {
+///     #[generator(yield(u8))]
+///     fn run (&self, step: u8)
+///     {
+///         let mut current = self.from;
+///         while let Some(next) = current.checked_sub(step) {
+///             yield_!(current);
+///             current = next;
+///         }
+///     }
+/// }
+///
+/// let countdown = Countdown { from: 6 };
+/// mk_gen!(let generator = countdown.run(2));
+/// assert_eq!(generator.into_iter().collect::<Vec<_>>(), [6, 4, 2]);
+/// ```
+///
 /// See [`GeneratorFn`] for more examples.
 #[macro_export]
 macro_rules! mk_gen {
@@ -86,12 +117,13 @@ macro_rules! mk_gen {
         let mut var = $crate::__::alloc::boxed::Box::pin(
             $crate::generator_fn::GeneratorFn::empty()
         );
-        var .as_mut()
-            .init(
-                $generator,
-                ($($args, )*),
-            )
-        ;
+        {
+            #[allow(unused_imports)]
+            use $crate::__::{InitViaInit as _, InitViaScoped as _};
+            (&&&$crate::__::InitScoped::new($generator))
+                .__init(var.as_mut(), ($($args, )*))
+            ;
+        }
         let $($mut)? $var = var;
     );
 
@@ -102,12 +134,50 @@ macro_rules! mk_gen {
     ) => (
         let var = $crate::generator_fn::GeneratorFn::empty();
         $crate::stack_pinned!(mut var);
+        {
+            #[allow(unused_imports)]
+            use $crate::__::{InitViaInit as _, InitViaScoped as _};
+            (&&&$crate::__::InitScoped::new($generator))
+                .__init(var.as_mut(), ($($args, )*))
+            ;
+        }
+        let $($mut)? $var = var;
+    );
+
+    (@input
+        let [$($mut:tt)?] $var:ident =
+            box $($receiver:tt).+ ( $($args:expr),* $(,)? )
+        $(;)?
+    ) => (
+        let (generator_fn, args) =
+            $crate::generator_fn::GeneratorMethodCall::into_parts(
+                $($receiver).+ ( $($args),* )
+            )
+        ;
+        let mut var = $crate::__::alloc::boxed::Box::pin(
+            $crate::generator_fn::GeneratorFn::empty()
+        );
         var .as_mut()
-            .init(
-                $generator,
-                ($($args, )*),
+            .init(generator_fn, args)
+        ;
+        let $($mut)? $var = var;
+    );
+
+    (@input
+        let [$($mut:tt)?] $var:ident =
+            $($receiver:tt).+ ( $($args:expr),* $(,)? )
+        $(;)?
+    ) => (
+        let (generator_fn, args) =
+            $crate::generator_fn::GeneratorMethodCall::into_parts(
+                $($receiver).+ ( $($args),* )
             )
         ;
+        let var = $crate::generator_fn::GeneratorFn::empty();
+        $crate::stack_pinned!(mut var);
+        var .as_mut()
+            .init(generator_fn, args)
+        ;
         let $($mut)? $var = var;
     );
 
@@ -124,10 +194,27 @@ macro_rules! mk_gen {
     );
 }
 
+/// Same as [`mk_gen!`], but for [`#[coroutine]`][`macro@crate::coroutine`]s.
+///
+/// # Usage
+///
+/// > `mk_coro!(let $(mut)? <varname> = $(box)? <coroutine fn> (<args>));`
+///
+/// where the `<args>` do not include the `yield_` parameter, since the initial
+/// resume argument is the one given to the first
+/// [`.resume_with()`][`crate::coroutine::Coroutine::resume_with`].
+#[macro_export]
+macro_rules! mk_coro {( $($input:tt)* ) => (
+    $crate::mk_gen!($($input)*)
+)}
+
 /// Emulate a `for`-loop iteration over a generator. The call itself evaluates
 /// to the [`Return`][`crate::Generator::Return`] value of the [`Generator`][
 /// `crate::Generator`].
 ///
+/// Besides a generator function call, it accepts anything
+/// [`IntoGenerator`][`crate::generator::IntoGenerator`] (resumed with `()`).
+///
 /// # Example
 ///
 /// ```rust
@@ -170,6 +257,18 @@ macro_rules! mk_gen {
 ///     }
 /// );
 /// assert_eq!(ret, 42);
+///
+/// // Or anything `IntoGenerator`:
+///
+/// use ::next_gen::generator::Iterable;
+///
+/// let (numbers, mut sum) = (Iterable(vec![1, 2, 3]), 0);
+/// let () = gen_iter!(
+///     for x in numbers {
+///         sum += x;
+///     }
+/// );
+/// assert_eq!(sum, 6);
 /// ```
 /// ___
 ///
@@ -215,19 +314,102 @@ macro_rules! gen_iter {
         }
     });
 
+    (
+        for $pat:pat
+            in $($receiver:tt).+ ($($args:expr),* $(,)?)
+        $block:block
+    ) => ({
+        $crate::mk_gen! {
+            let generator = $($receiver).+ ($($args),*)
+        }
+        $crate::gen_iter! {
+            for $pat in generator
+                $block
+        }
+    });
+
     (
         for $pat:pat in $generator:tt $block:block
     ) => (
-        match $generator { mut generator => loop {
-            match
-                $crate::generator::Generator::resume(
-                    $crate::__::core::pin::Pin::as_mut(&mut generator),
-                    (),
-                )
-            {
-                | $crate::generator::GeneratorState::Yielded($pat) => $block,
-                | $crate::generator::GeneratorState::Returned(ret) => break ret,
-            }
-        }}
+        match
+            <_ as $crate::generator::IntoGenerator<()>>::into_generator(
+                $generator
+            )
+        {
+            generator => {
+                $crate::stack_pinned!(mut generator);
+                loop {
+                    match
+                        $crate::generator::Generator::resume(
+                            generator.as_mut(),
+                            (),
+                        )
+                    {
+                        | $crate::generator::GeneratorState::Yielded($pat) => {
+                            $block
+                        },
+                        | $crate::generator::GeneratorState::Returned(ret) => {
+                            break ret;
+                        },
+                    }
+                }
+            },
+        }
     );
 }
+
+/// Yields a value from within a [`#[generator]`][`macro@crate::generator`]
+/// (or [`gen!`][`crate::gen`]) body, evaluating to the next resume argument.
+///
+/// This macro forwards to a helper macro which the `#[generator]` attribute
+/// (re)defines within the body of the function it annotates: outside such a
+/// body, it only yields a proper error message.
+///
+/// ```rust,compile_fail
+/// use ::next_gen::prelude::*;
+///
+/// fn not_a_generator ()
+/// {
+///     yield_!(42); // Error, `yield_!` used outside a `#[generator]` fn
+/// }
+/// ```
+#[doc(hidden)] /** Exported as `prelude::yield_` */
+#[macro_export]
+macro_rules! __yield__ {( $($args:tt)* ) => (
+    __next_gen_yield__! { $($args)* }
+)}
+
+/// Yields all the values of a sub-generator (or iterable) from within a
+/// [`#[generator]`][`macro@crate::generator`] (or [`gen!`][`crate::gen`])
+/// body, evaluating to the sub-generator's return value.
+///
+/// > `yield_from!(<generator fn> (<args>) $(, <first resume arg>)?)`
+/// >
+/// > `yield_from!(<impl IntoGenerator> $(, <first resume arg>)?)`
+///
+/// where the first resume argument defaults to `()`.
+///
+/// As with [`yield_!`][`crate::prelude::yield_`], this macro forwards to a
+/// helper macro (re)defined by the `#[generator]` attribute within the body of
+/// the function it annotates.
+#[doc(hidden)] /** Exported as `prelude::yield_from` */
+#[macro_export]
+macro_rules! __yield_from__ {( $($args:tt)* ) => (
+    __next_gen_yield_from__! { $($args)* }
+)}
+
+#[doc(hidden)] /** Shadowed within `#[generator]` bodies. */
+#[macro_export]
+macro_rules! __next_gen_yield__ {( $($_:tt)* ) => (
+    $crate::__::core::compile_error! {
+        "`yield_!` can only be used inside a `#[generator]` fn (or `gen!`) body"
+    }
+)}
+
+#[doc(hidden)] /** Shadowed within `#[generator]` bodies. */
+#[macro_export]
+macro_rules! __next_gen_yield_from__ {( $($_:tt)* ) => (
+    $crate::__::core::compile_error! {
+        "`yield_from!` can only be used inside a `#[generator]` fn (or `gen!`) body"
+    }
+)}
@@ -1,4 +1,7 @@
 #![allow(unused_imports)]
+#![allow(clippy::let_unit_value, clippy::needless_arbitrary_self_type)]
+// The `extern {}` blocks hosting the safety comments of `stack_pinned!`.
+#![allow(unknown_lints, missing_abi, unused_doc_comments)]
 use {
     ::core::{
         iter::FromIterator,
@@ -10,6 +13,9 @@ use {
     super::*,
 };
 
+#[cfg(feature = "nightly")]
+mod native;
+
 #[test]
 fn basic ()
 {
@@ -94,195 +100,2308 @@ fn range ()
     );
 }
 
-mod proc_macros {
+mod gen_from_async {
     use super::{*, panic};
     use ::next_gen_proc_macros::generator;
+    use crate::{
+        gen_from_async::{Co, from_async},
+        generator_fn::CallBoxed as _,
+    };
 
-    #[test]
-    fn resume_args ()
+    async
+    fn words<'text> (co: &'_ Co<'_, &'text str>, line: &'text str)
+      -> usize
     {
-        #[generator(yield(u8), resume(i32) as mut arg)]
-        fn generator<'foo> ()
-          -> Vec<i32>
-        {
-            let mut resume_args = vec![];
-            while arg != 0 {
-                resume_args.push(arg);
-                arg = yield_!(arg as _);
-            }
+        let mut count = 0;
+        for word in line.split_whitespace() {
+            co.yield_(word).await;
+            count += 1;
+        }
+        count
+    }
 
-            resume_args
+    async
+    fn all_words<'text> (co: Co<'_, &'text str>, (text, ): (&'text str, ))
+      -> usize
+    {
+        let mut count = 0;
+        for line in text.lines() {
+            count += words(&co, line).await;
         }
+        count
+    }
 
-        mk_gen!(let mut generator = generator());
-        let mut resume = |arg| match generator.as_mut().resume(arg) {
-            | GeneratorState::Yielded(yielded_value) => {
-                assert_eq!(yielded_value as i32, arg);
-                None
-            },
-            | GeneratorState::Returned(ret) => {
-                assert_eq!(arg, 0);
-                Some(ret)
-            },
+    #[test]
+    fn borrowing_helpers ()
+    {
+        let text = String::from("Hello, World!\nHow are\n\nyou?");
+        mk_gen!(let mut generator = from_async(all_words, (&text[..], )));
+        let mut yielded = vec![];
+        let count = loop {
+            match generator.as_mut().resume(()) {
+                | GeneratorState::Yielded(word) => yielded.push(word),
+                | GeneratorState::Returned(count) => break count,
+            }
         };
-
-        resume(12);
-        resume(17);
-        resume(47);
-        assert_eq!(resume(0).unwrap(), vec![12, 17, 47]);
+        assert_eq!(yielded, ["Hello,", "World!", "How", "are", "you?"]);
+        assert_eq!(count, 5);
     }
 
     #[test]
-    fn range ()
+    fn resume_args ()
     {
-        #[generator(yield(u8))]
-        fn range (start: u8, end: u8)
+        async
+        fn echo (co: Co<'_, String, String>, (): ())
+          -> Vec<String>
         {
-            let mut current = start;
-            while current < end {
-                yield_!(current);
-                current += 1;
+            let mut seen = vec![];
+            let mut arg = co.take_initial_resume_arg().unwrap();
+            assert!(co.take_initial_resume_arg().is_none());
+            while arg.is_empty().not() {
+                seen.push(arg.clone());
+                arg = co.yield_(arg).await;
             }
+            seen
         }
 
-        mk_gen!(let generator = range(2, 8));
-        assert_it_eq!(
-            generator,
-            Vec::from_iter(2 .. 8),
+        let mut generator = from_async.call_boxed((echo, ()));
+        let mut resume = |s: &str| generator.as_mut().resume(s.to_owned());
+        assert_eq!(resume("a"), GeneratorState::Yielded("a".to_owned()));
+        assert_eq!(resume("b"), GeneratorState::Yielded("b".to_owned()));
+        assert_eq!(
+            resume(""),
+            GeneratorState::Returned(vec!["a".to_owned(), "b".to_owned()]),
         );
     }
 
     #[test]
-    fn gen_iter ()
+    fn within_yield_from ()
     {
-        type Question = &'static str;
-        type Answer = i32;
+        async
+        fn countdown (co: Co<'_, u8>, (from, ): (u8, ))
+        {
+            for n in (0 ..= from).rev() {
+                co.yield_(n).await;
+            }
+        }
 
-        #[generator(yield(Question))]
-        fn answer ()
-          -> Answer
+        #[generator(yield(u8))]
+        fn twice (from: u8)
         {
-            yield_!("What is the answer to life, the universe and everything?");
-            42
+            yield_from!(from_async(countdown, (from, )));
+            yield_from!(from_async(countdown, (from, )));
         }
 
-        let ret = gen_iter!(
-            for question in answer() {
-                assert_eq!(
-                    question,
-                    "What is the answer to life, the universe and everything?",
-                );
-            }
-        );
-        assert_eq!(ret, 42);
+        mk_gen!(let generator = twice(2));
+        assert_it_eq!(generator, [2, 1, 0, 2, 1, 0]);
     }
 
-    mod adaptors {
-        use super::{*, panic};
-
-        #[generator(yield(T))]
-        fn filter<T> (
-            mut predicate: impl FnMut(&T) -> bool,
-            iterable: impl IntoIterator<Item = T>,
-        )
+    #[test]
+    fn send ()
+    {
+        fn is_send<T : Send> (it: T)
+          -> T
         {
-            for element in iterable {
-                if predicate(&element) {
-                    yield_!(element);
-                }
-            }
+            it
         }
 
-        #[generator(yield(U))]
-        fn map<T, U> (
-            mut f: impl FnMut(T) -> U,
-            iterable: impl IntoIterator<Item = T>,
-        )
+        async
+        fn numbers (co: Co<'_, u32>, (count, ): (u32, ))
         {
-            for element in iterable {
-                yield_!(f(element));
+            for n in 0 .. count {
+                let yield_ = co.yield_(n);
+                yield_.await;
             }
         }
 
-        #[generator(yield(u8))]
-        fn range (start: u8, end: u8)
-        {
-            let mut current = start;
-            while current < end {
-                yield_!(current);
-                current += 1;
+        let generator = is_send(from_async.call_boxed((numbers, (3, ))));
+        let sum = ::std::thread::spawn(move || {
+            let mut generator = generator;
+            generator.as_mut().sum::<u32>()
+        }).join().unwrap();
+        assert_eq!(sum, 3);
+    }
+}
+
+mod lifecycle {
+    use super::{*, panic};
+    use ::next_gen_proc_macros::generator;
+    use crate::generator_fn::{GeneratorFnState, ResumeError};
+
+    #[generator(yield(u8))]
+    fn countdown (from: u8)
+      -> &'static str
+    {
+        for n in (1 ..= from).rev() {
+            if n == 13 {
+                panic!("Unlucky!");
             }
+            yield_!(n);
         }
+        "Liftoff!"
+    }
 
-        #[test]
-        fn filter_range ()
-        {
+    #[test]
+    fn states ()
+    {
+        mk_gen!(let mut generator = countdown(2));
+        assert_eq!(generator.state(), GeneratorFnState::Unstarted);
+        assert_eq!(generator.as_mut().resume(()), GeneratorState::Yielded(2));
+        assert_eq!(generator.state(), GeneratorFnState::Suspended);
+        assert_eq!(generator.as_mut().resume(()), GeneratorState::Yielded(1));
+        assert_eq!(
+            generator.as_mut().resume(()),
+            GeneratorState::Returned("Liftoff!"),
+        );
+        assert_eq!(generator.state(), GeneratorFnState::Completed);
+        assert_eq!(
+            generator.as_mut().try_resume(()),
+            Err(ResumeError::Completed),
+        );
+    }
 
-            mk_gen!(let iterator = range(2, 7));
-            mk_gen!(let iterator = filter(|x| x % 2 == 0, iterator));
-            assert_it_eq!(
-                iterator,
-                [2, 4, 6],
-            );
+    #[test]
+    fn empty ()
+    {
+        type Body = Pin<Box<dyn Future<Output = ()>>>;
+        let generator = GeneratorFn::<u8, Body, ()>::empty();
+        stack_pinned!(mut generator);
+        assert_eq!(generator.state(), GeneratorFnState::Empty);
+        assert_eq!(
+            generator.as_mut().try_resume(()),
+            Err(ResumeError::Empty),
+        );
+    }
+
+    #[test]
+    fn poisoned ()
+    {
+        mk_gen!(let mut generator = countdown(14));
+        assert_eq!(generator.as_mut().resume(()), GeneratorState::Yielded(14));
+        let caught = panic::catch_unwind(panic::AssertUnwindSafe(|| {
+            generator.as_mut().resume(())
+        }));
+        assert!(caught.is_err());
+        assert_eq!(generator.state(), GeneratorFnState::Poisoned);
+        assert_eq!(
+            generator.as_mut().try_resume(()),
+            Err(ResumeError::Poisoned),
+        );
+    }
+
+    #[test]
+    fn resume_catching ()
+    {
+        mk_gen!(let mut generator = countdown(14));
+        let mut next = || generator.as_mut().resume_catching(());
+        assert_eq!(next().unwrap(), GeneratorState::Yielded(14));
+        let payload = next().unwrap_err();
+        assert_eq!(payload.downcast_ref::<&str>(), Some(&"Unlucky!"));
+        let payload = next().unwrap_err();
+        assert_eq!(
+            payload.downcast_ref::<String>().map(|s| &s[..]),
+            Some("`GeneratorFn` resumed after panicking"),
+        );
+    }
+
+    #[test]
+    fn unused_resume_arg_is_dropped_on_panic ()
+    {
+        use ::std::rc::Rc;
+
+        async
+        fn panicky (_: YieldSlot<'_, (), Rc<()>>, (): ())
+        {
+            panic!("before taking the resume arg");
         }
 
-        #[test]
-        fn filter_map_range ()
+        let rc = Rc::new(());
+        mk_gen!(let mut generator = panicky());
+        assert!(generator.as_mut().resume_catching(Rc::clone(&rc)).is_err());
+        assert_eq!(generator.state(), GeneratorFnState::Poisoned);
+        assert_eq!(Rc::strong_count(&rc), 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "`GeneratorFn` resumed after completion")]
+    fn resume_after_completion ()
+    {
+        mk_gen!(let mut generator = countdown(0));
+        assert_eq!(
+            generator.as_mut().resume(()),
+            GeneratorState::Returned("Liftoff!"),
+        );
+        generator.as_mut().resume(());
+    }
+
+    #[test]
+    fn async_ ()
+    {
+        #[generator(yield(u8), async)]
+        fn single ()
         {
+            yield_!(42);
+        }
 
-            mk_gen!(let iterator = range(2, 7));
-            mk_gen!(let iterator = filter(|x| x % 2 == 0, iterator));
-            mk_gen!(let iterator = map(|x| x * x, iterator));
-            assert_it_eq!(
-                iterator,
-                [4, 16, 36],
-            );
+        mk_gen!(let mut generator = single());
+        crate::utils::macros::create_context!(cx);
+        let mut next = || generator.as_mut().try_poll_resume(&mut cx);
+        assert_eq!(next(), Poll::Ready(Ok(GeneratorState::Yielded(42))));
+        assert_eq!(next(), Poll::Ready(Ok(GeneratorState::Returned(()))));
+        assert_eq!(next(), Poll::Ready(Err(ResumeError::Completed)));
+    }
+}
+
+mod generator_state {
+    use super::{*, panic};
+
+    type State = GeneratorState<u8, &'static str>;
+
+    #[test]
+    fn accessors ()
+    {
+        let yielded = State::Yielded(42);
+        let returned = State::Returned("done");
+        assert!(yielded.is_yielded() && returned.is_returned());
+        assert!(yielded.is_returned().not() && returned.is_yielded().not());
+        assert_eq!(yielded.yielded(), Some(42));
+        assert_eq!(returned.yielded(), None);
+        assert_eq!(yielded.returned(), None);
+        assert_eq!(returned.returned(), Some("done"));
+        assert_eq!(yielded.as_ref(), GeneratorState::Yielded(&42));
+        let mut state = yielded;
+        if let GeneratorState::Yielded(it) = state.as_mut() {
+            *it += 1;
         }
+        assert_eq!(state, State::Yielded(43));
     }
 
     #[test]
-    fn return_iterator_with_concrete_dyn_type ()
+    fn conversions ()
     {
-        enum Void {}
-        type None = Option<Void>;
+        let yielded = State::Yielded(42);
+        let returned = State::Returned("done");
+        assert_eq!(yielded.map_yielded(|x| x * 2), State::Yielded(84));
+        assert_eq!(returned.map_yielded(|x| x * 2), returned);
+        assert_eq!(
+            returned.map_returned(str::len),
+            GeneratorState::<u8, _>::Returned(4),
+        );
+        assert_eq!(yielded.into_result(), Ok(42));
+        assert_eq!(returned.into_result(), Err("done"));
+        assert_eq!(State::from(Ok(42)), yielded);
+        assert_eq!(GeneratorState::<u8, u8>::Returned(0).into_inner(), 0);
+        assert_eq!(
+            Poll::from(GeneratorState::<(), _>::Returned(42)),
+            Poll::Ready(42),
+        );
+        assert_eq!(
+            GeneratorState::from(Poll::<u8>::Pending),
+            GeneratorState::Yielded(()),
+        );
+        #[cfg(feature = "control-flow")] {
+            use ::core::ops::ControlFlow;
+            assert_eq!(ControlFlow::from(yielded), ControlFlow::Continue(42));
+            assert_eq!(State::from(ControlFlow::Break("done")), returned);
+        }
+    }
+}
 
-        trait Countdown {
-            type Iter : Iterator<Item = u8>;
-            fn countdown (self: &'_ Self)
-              -> Self::Iter
-            ;
+mod iter {
+    use super::{*, panic};
+    use crate::generator::GeneratorExt as _;
+
+    #[generator(yield(u8))]
+    fn countdown (n: u8)
+      -> &'static str
+    {
+        for i in (1 ..= n).rev() {
+            yield_!(i);
         }
+        "Liftoff!"
+    }
 
-        struct CountdownFrom(u8);
-        impl Countdown for CountdownFrom {
-            type Iter = Pin<Box<
-                dyn Generator<(), Yield = u8, Return = None>
-            >>;
+    #[test]
+    fn fused_and_keeps_return ()
+    {
+        mk_gen!(let mut generator = countdown(2));
+        assert_eq!(generator.by_ref().collect::<Vec<_>>(), [2, 1]);
+        assert_eq!(generator.next(), None);
+        assert_eq!(generator.next(), None);
+        assert_eq!(generator.as_mut().take_return(), Some("Liftoff!"));
+        assert_eq!(generator.as_mut().take_return(), None);
 
-            fn countdown (self: &'_ CountdownFrom)
-              -> Pin<Box<
-                    dyn Generator<(), Yield = u8, Return = None>
-                >>
-            {
-                #[generator(yield(u8))]
-                fn countdown (from: u8)
-                  -> None
-                {
-                    let mut current = from;
-                    loop {
-                        yield_!(current);
-                        current = current.checked_sub(1)?;
-                    }
-                }
-                mk_gen!(let countdown = box countdown(self.0));
-                countdown
+        mk_gen!(let mut generator = box countdown(1));
+        assert_eq!(generator.next(), Some(1));
+        assert_eq!(generator.next(), None);
+        assert_eq!(generator.next(), None);
+        assert_eq!(generator.as_mut().take_return(), Some("Liftoff!"));
+    }
+
+    #[test]
+    fn dyn_iter_pin ()
+    {
+        use crate::generator::{DynGenerator, IterPin};
+
+        mk_gen!(let generator = box countdown(2));
+        let boxed: Pin<Box<DynGenerator<'_, u8, &str>>> = generator;
+        let mut iter = IterPin::new(boxed);
+        assert_eq!(iter.by_ref().collect::<Vec<_>>(), [2, 1]);
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.take_return(), Some("Liftoff!"));
+        assert_eq!(iter.take_return(), None);
+    }
+
+    #[test]
+    fn iter_pin ()
+    {
+        mk_gen!(let generator = box countdown(3));
+        let mut iter = generator.boxed_gen_into_iter();
+        assert_eq!(iter.by_ref().take(3).sum::<u8>(), 6);
+        assert_eq!(iter.take_return(), None);
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.take_return(), Some("Liftoff!"));
+        assert_eq!(iter.take_return(), None);
+
+        mk_gen!(let generator = countdown(1));
+        let mut iter = generator.gen_into_iter();
+        assert_eq!(iter.next(), Some(1));
+        let mut generator = iter.into_inner();
+        assert_eq!(generator.as_mut().resume(()).returned(), Some("Liftoff!"));
+
+        mk_gen!(let generator = countdown(0));
+        let mut iter = generator.gen_into_iter();
+        assert_eq!(iter.by_ref().last(), None);
+        assert_eq!(iter.into_return(), Some("Liftoff!"));
+    }
+}
+
+mod generator_adaptors {
+    use super::{*, panic};
+    use crate::{
+        generator::{
+            adaptors::{Either, ZipReturn},
+            GeneratorExt, IntoGenerator, Iterable,
+        },
+        generator_fn::CallBoxed,
+    };
+
+    /// Yields the running sum of the numbers it is fed with, until fed `0`.
+    #[generator(yield(u32), resume(u32) as first)]
+    fn summer ()
+      -> u32
+    {
+        let mut sum = first;
+        loop {
+            match yield_!(sum) {
+                | 0 => return sum,
+                | n => sum += n,
             }
         }
-        assert_it_eq!(
-            CountdownFrom(3).countdown(),
-            [3, 2, 1, 0],
+    }
+
+    #[generator(yield(u8))]
+    fn range (start: u8, end: u8)
+      -> &'static str
+    {
+        for i in start .. end {
+            yield_!(i);
+        }
+        "done"
+    }
+
+    #[test]
+    fn resume_args ()
+    {
+        mk_gen!(let generator = summer());
+        let mut generator =
+            generator
+                .map_yield(|sum| sum * 10)
+                .filter_yield(|&sum| sum != 30)
+                .map_return(|sum| sum + 1)
+        ;
+        assert_eq!(generator.resume_unpin(1), GeneratorState::Yielded(10));
+        // 1 + 2 = 3 is filtered out, so the generator is resumed anew with 2.
+        assert_eq!(generator.resume_unpin(2), GeneratorState::Yielded(50));
+        assert_eq!(generator.resume_unpin(0), GeneratorState::Returned(6));
+    }
+
+    #[test]
+    fn inspect_enumerate_take_while ()
+    {
+        let mut seen = vec![];
+        mk_gen!(let generator = range(0, 10));
+        let mut generator =
+            generator.inspect_yield(|&i| seen.push(i))
+                .enumerate_yield()
+                .take_while_yield(|&(i, x)| i < 2 && x < 5)
+        ;
+        assert_eq!(generator.resume_unpin(()), GeneratorState::Yielded((0, 0)));
+        assert_eq!(generator.resume_unpin(()), GeneratorState::Yielded((1, 1)));
+        assert_eq!(
+            generator.resume_unpin(()),
+            GeneratorState::Returned(Either::Right((2, 2))),
+        );
+        assert!(
+            panic::catch_unwind(panic::AssertUnwindSafe(|| {
+                generator.resume_unpin(())
+            }))
+            .is_err()
+        );
+        drop(generator);
+        assert_eq!(seen, [0, 1, 2]);
+
+        mk_gen!(let generator = range(0, 1));
+        let mut generator = generator.take_while_yield(|_| true);
+        assert_eq!(generator.resume_unpin(()), GeneratorState::Yielded(0));
+        assert_eq!(
+            generator.resume_unpin(()),
+            GeneratorState::Returned(Either::Left("done")),
         );
     }
+
+    #[test]
+    fn peekable ()
+    {
+        mk_gen!(let generator = summer());
+        let generator = generator.peekable_yield();
+        stack_pinned!(mut generator);
+        assert_eq!(generator.as_mut().peek(1), GeneratorState::Yielded(&1));
+        // Already peeked: the resume args are discarded.
+        assert_eq!(generator.as_mut().peek(42), GeneratorState::Yielded(&1));
+        assert_eq!(generator.as_mut().resume(42), GeneratorState::Yielded(1));
+        assert_eq!(generator.as_mut().resume(2), GeneratorState::Yielded(3));
+        assert_eq!(generator.as_mut().peek(0), GeneratorState::Returned(&3));
+        assert_eq!(generator.as_mut().resume(7), GeneratorState::Returned(3));
+    }
+
+    #[test]
+    fn chain ()
+    {
+        mk_gen!(let generator = summer());
+        let mut generator = generator.chain_yield(|sum| {
+            summer.call_boxed(()).map_yield(move |it| it + sum)
+        });
+        assert_eq!(generator.resume_unpin(1), GeneratorState::Yielded(1));
+        // The first generator returns, and the second one is started with 0.
+        assert_eq!(generator.resume_unpin(0), GeneratorState::Yielded(1));
+        assert_eq!(generator.resume_unpin(2), GeneratorState::Yielded(3));
+        assert_eq!(generator.resume_unpin(1), GeneratorState::Yielded(4));
+        assert_eq!(generator.resume_unpin(0), GeneratorState::Returned(3));
+    }
+
+    #[test]
+    fn zip ()
+    {
+        mk_gen!(let a = summer());
+        mk_gen!(let b = box summer());
+        let mut generator = a.zip_yield(b.map_return(|it| it * 2));
+        assert_eq!(generator.resume_unpin(1), GeneratorState::Yielded((1, 1)));
+        assert_eq!(generator.resume_unpin(2), GeneratorState::Yielded((3, 3)));
+        assert_eq!(
+            generator.resume_unpin(0),
+            GeneratorState::Returned(ZipReturn::First(3)),
+        );
+
+        // The other generator is left as it was, even if it never returns.
+        let mut seen = vec![];
+        mk_gen!(let a = range(0, 1));
+        let b = Iterable(0 ..).into_generator();
+        let b = b.inspect_yield(|&i| seen.push(i));
+        let mut generator = a.zip_yield(b);
+        assert_eq!(generator.resume_unpin(()), GeneratorState::Yielded((0, 0)));
+        assert_eq!(
+            generator.resume_unpin(()),
+            GeneratorState::Returned(ZipReturn::First("done")),
+        );
+        let (_, mut b) = generator.into_inner();
+        assert_eq!(b.resume_unpin(()), GeneratorState::Yielded(1));
+        drop(b);
+        assert_eq!(seen, [0, 1]);
+
+        // The first generator's pending item is kept.
+        mk_gen!(let a = range(0, 3));
+        mk_gen!(let b = range(0, 1));
+        let mut generator = a.zip_yield(b);
+        assert_eq!(generator.resume_unpin(()), GeneratorState::Yielded((0, 0)));
+        assert_eq!(
+            generator.resume_unpin(()),
+            GeneratorState::Returned(ZipReturn::Second(1, "done")),
+        );
+    }
+
+    #[test]
+    fn feed ()
+    {
+        mk_gen!(let generator = summer());
+        let mut sums = generator.feed(vec![1, 2]);
+        assert_eq!(sums.by_ref().collect::<Vec<_>>(), [1, 3]);
+        // Ran out of resume args: fused, but without a return value.
+        assert_eq!(sums.next(), None);
+        assert_eq!(sums.take_return(), None);
+        let mut sums = sums.into_inner().feed(vec![3, 0, 42]);
+        assert_eq!(sums.by_ref().collect::<Vec<_>>(), [6]);
+        assert_eq!(sums.next(), None);
+        assert_eq!(sums.take_return(), Some(6));
+        assert_eq!(sums.take_return(), None);
+
+        let mut resume_args = vec![0, 5, 1].into_iter();
+        mk_gen!(let generator = box summer());
+        let mut sums = generator.with_resume_fn(|| resume_args.next().unwrap());
+        assert_eq!(sums.next(), Some(0));
+        assert_eq!(sums.next(), Some(5));
+        assert_eq!(sums.next(), Some(6));
+        let mut generator = sums.into_inner();
+        assert_eq!(generator.resume_unpin(0), GeneratorState::Returned(6));
+    }
+
+    #[test]
+    fn map_resume_and_drive ()
+    {
+        mk_gen!(let generator = summer());
+        let generator = generator.map_resume(|s: &str| s.len() as u32);
+        stack_pinned!(mut generator);
+        let mut seen = vec![];
+        let sum = generator.as_mut().drive("Hello", |sum| {
+            seen.push(sum);
+            if sum < 10 { "World!" } else { "" }
+        });
+        assert_eq!(seen, [5, 11]);
+        assert_eq!(sum, 11);
+    }
+}
+
+mod generator_merge {
+    use super::{*, panic};
+    use {
+        ::core::cell::Cell,
+        crate::{
+            generator::{merge, GeneratorExt, LocalBoxGenerator},
+            generator_fn::CallBoxed,
+        },
+    };
+
+    type BoxedGen<'lt> = LocalBoxGenerator<'lt, (u8, char), char>;
+
+    #[generator(yield((u8, char)))]
+    fn source (name: char, keys: &'static [u8])
+      -> char
+    {
+        for &key in keys {
+            yield_!((key, name));
+        }
+        name
+    }
+
+    fn sources (all_keys: &'_ [&'static [u8]])
+      -> Vec<BoxedGen<'static>>
+    {
+        all_keys
+            .iter()
+            .zip("abcdef".chars())
+            .map(|(&keys, name)| source.call_boxed((name, keys)) as BoxedGen)
+            .collect()
+    }
+
+    fn drain<G : Generator<()> + Unpin> (mut generator: G)
+      -> (Vec<G::Yield>, G::Return)
+    {
+        let mut yielded = vec![];
+        let ret = Pin::new(&mut generator).drive((), |it| yielded.push(it));
+        (yielded, ret)
+    }
+
+    #[test]
+    fn interleave ()
+    {
+        mk_gen!(let a = source('a', &[1, 2, 3]));
+        mk_gen!(let b = source('b', &[4]));
+        let (yielded, ret) = drain(merge::interleave(a, b));
+        assert_eq!(yielded, [(1, 'a'), (4, 'b'), (2, 'a'), (3, 'a')]);
+        assert_eq!(ret, ('a', 'b'));
+    }
+
+    #[test]
+    fn round_robin ()
+    {
+        let (yielded, ret) = drain(merge::round_robin(sources(&[
+            &[1, 2],
+            &[],
+            &[3, 4, 5],
+        ])));
+        assert_eq!(yielded, [(1, 'a'), (3, 'c'), (2, 'a'), (4, 'c'), (5, 'c')]);
+        assert_eq!(ret, ['a', 'b', 'c']);
+
+        let (yielded, ret) = drain(merge::round_robin(sources(&[])));
+        assert!(yielded.is_empty() && ret.is_empty());
+    }
+
+    #[test]
+    fn merge_sorted_by ()
+    {
+        let (yielded, ret) = drain(merge::merge_sorted_by(
+            sources(&[&[1, 3, 3], &[], &[0, 3, 7], &[2]]),
+            |&(key, _)| key,
+        ));
+        // Stable: ties are resolved in favor of the earliest source.
+        assert_eq!(yielded, [
+            (0, 'c'), (1, 'a'), (2, 'd'),
+            (3, 'a'), (3, 'a'), (3, 'c'),
+            (7, 'c'),
+        ]);
+        assert_eq!(ret, ['a', 'b', 'c', 'd']);
+
+        // Sources are not resumed further than needed.
+        let yields = Cell::new(0);
+        let counted = |name, keys| Box::pin(
+            source
+                .call_boxed((name, keys))
+                .inspect_yield(|_| yields.set(yields.get() + 1))
+        ) as BoxedGen<'_>;
+        let mut generator = merge::merge_sorted_by(
+            vec![counted('a', &[0, 1]), counted('b', &[2])],
+            |&(key, _)| key,
+        );
+        assert_eq!(yields.get(), 0);
+        assert_eq!(generator.resume_unpin(()).yielded(), Some((0, 'a')));
+        assert_eq!(yields.get(), 2);
+        assert_eq!(generator.resume_unpin(()).yielded(), Some((1, 'a')));
+        assert_eq!(yields.get(), 3);
+    }
+
+    #[test]
+    fn select ()
+    {
+        let mut generator = merge::select(sources(&[&[1, 2, 3], &[4, 5]]));
+        let (yielded, ret) = drain(&mut generator);
+        assert_eq!(yielded, [(1, 'a'), (4, 'b'), (2, 'a'), (5, 'b'), (3, 'a')]);
+        assert_eq!(ret, (1, 'b'));
+        let mut sources = generator.into_sources();
+        assert_eq!(
+            sources[0].resume_unpin(()),
+            GeneratorState::Returned('a'),
+        );
+    }
+}
+
+mod generator_sources {
+    use super::{*, panic};
+    use crate::generator::{sources, GeneratorExt};
+
+    fn assert_unpin<G : Generator<R> + Unpin, R> (_: &'_ G)
+    {}
+
+    #[test]
+    fn unpin ()
+    {
+        // `!Unpin` captures, on purpose.
+        let pinned = PhantomPinned;
+        let mut generator = sources::from_fn(move |()| {
+            let _ = &pinned;
+            GeneratorState::<(), ()>::Returned(())
+        });
+        assert_unpin::<_, ()>(&generator);
+        assert_unpin::<_, ()>(&sources::unfold(PhantomPinned, |_, ()| {
+            GeneratorState::<(), ()>::Returned(())
+        }));
+        assert_unpin(&sources::from_iter(vec![PhantomPinned]));
+        assert_unpin(&sources::once(PhantomPinned));
+        assert_unpin(&sources::repeat_with(|| PhantomPinned));
+        assert_unpin(&sources::empty::<PhantomPinned, ()>());
+        // The `&mut G` blanket impl.
+        let mut by_ref = &mut generator;
+        assert_eq!(by_ref.resume_unpin(()), GeneratorState::Returned(()));
+        assert!(Pin::new(&mut by_ref).resume(()).is_returned());
+    }
+
+    #[test]
+    fn from_iter ()
+    {
+        let mut generator = sources::from_iter(vec![1, 2, 3]);
+        let iter = Pin::new(&mut generator).gen_into_iter();
+        assert_eq!(iter.sum::<i32>(), 6);
+        assert_eq!(generator.resume_unpin(()), GeneratorState::Returned(3));
+
+        let generator = sources::from_iter(1 ..);
+        let mut squares =
+            generator
+                .map_yield(|n: u32| n * n)
+                .take_while_yield(|&square| square < 10)
+        ;
+        assert_eq!(squares.resume_unpin(()), GeneratorState::Yielded(1));
+        assert_eq!(squares.resume_unpin(()), GeneratorState::Yielded(4));
+        assert_eq!(squares.resume_unpin(()), GeneratorState::Yielded(9));
+        assert!(squares.resume_unpin(()).is_returned());
+    }
+
+    #[test]
+    fn once_and_empty ()
+    {
+        let mut chained =
+            sources::once('a')
+                .chain_yield(|()| sources::once('b'))
+                .chain_yield(|()| sources::empty::<char, &str>())
+        ;
+        let mut yielded = vec![];
+        let ret = Pin::new(&mut chained).drive((), |c| yielded.push(c));
+        assert_eq!(yielded, ['a', 'b']);
+        assert_eq!(ret, "");
+    }
+}
+
+mod into_generator {
+    use super::{*, panic};
+    use crate::{
+        generator::{
+            adaptors::Either, merge, GeneratorExt, IntoGenerator, Iterable,
+        },
+        generator_fn::CallBoxed,
+    };
+
+    fn collect<G : IntoGenerator> (generator: G)
+      -> (Vec<G::Yield>, G::Return)
+    {
+        let generator = generator.into_generator();
+        stack_pinned!(mut generator);
+        let mut yielded = vec![];
+        let ret = generator.drive((), |it| yielded.push(it));
+        (yielded, ret)
+    }
+
+    #[generator(yield(u8))]
+    fn countdown (from: u8)
+      -> u8
+    {
+        for i in (1 ..= from).rev() {
+            yield_!(i);
+        }
+        from
+    }
+
+    #[test]
+    fn iterables ()
+    {
+        assert_eq!(collect(Iterable(vec!['a', 'b'])), (vec!['a', 'b'], ()));
+        assert_eq!(collect(Iterable(&[1])), (vec![&1], ()));
+        assert_eq!(collect(Iterable(None::<()>)), (vec![], ()));
+        let generator =
+            Iterable(0 ..).into_generator().take_while_yield(|&x| x < 2)
+        ;
+        assert_eq!(collect(generator), (vec![0, 1], Either::Right(2)));
+        let set = ::std::collections::BTreeSet::from_iter(vec![2, 1]);
+        assert_eq!(collect(Iterable(&set)), (vec![&1, &2], ()));
+        assert_eq!(collect(Iterable("ab".chars())), (vec!['a', 'b'], ()));
+    }
+
+    #[test]
+    fn generators ()
+    {
+        mk_gen!(let generator = countdown(2));
+        assert_eq!(collect(generator), (vec![2, 1], 2));
+        let generator = countdown.call_boxed((1, ));
+        assert_eq!(collect(generator.map_yield(u32::from)), (vec![1], 1));
+        let factory: fn(_, _) -> _ = countdown;
+        assert_eq!(collect((factory, (2, ))), (vec![2, 1], 2));
+    }
+
+    #[test]
+    fn combinators ()
+    {
+        mk_gen!(let generator = countdown(2));
+        let generator = generator.chain_yield(|n| Iterable(0 .. n));
+        let (yielded, ()) = collect(generator);
+        assert_eq!(yielded, [2, 1, 0, 1]);
+
+        mk_gen!(let generator = countdown(2));
+        let (yielded, _) = collect(generator.zip_yield(Iterable(vec!['a'])));
+        assert_eq!(yielded, [(2, 'a')]);
+
+        let (yielded, ret) = collect(merge::round_robin(vec![
+            Iterable(vec![0, 2, 4]),
+            Iterable(vec![1, 3]),
+        ]));
+        assert_eq!(yielded, [0, 1, 2, 3, 4]);
+        assert_eq!(ret, [(), ()]);
+
+        mk_gen!(let generator = countdown(3));
+        let (yielded, ret) =
+            collect(merge::interleave(generator, Iterable(10 ..= 11)))
+        ;
+        assert_eq!(yielded, [3, 10, 2, 11, 1]);
+        assert_eq!(ret, (3, ()));
+
+        let (numbers, mut sum) = (Iterable(1 ..= 3), 0);
+        let () = gen_iter!(for x in numbers {
+            sum += x;
+        });
+        assert_eq!(sum, 6);
+    }
+}
+
+mod boxed_generators {
+    use super::{*, panic};
+    use crate::{
+        generator::{
+            sources, BoxGenerator, DynGeneratorSend, GeneratorExt,
+            LocalBoxGenerator,
+        },
+        generator_fn::CallBoxed,
+    };
+
+    #[generator(yield(u8))]
+    fn countdown (from: u8)
+      -> &'static str
+    {
+        for n in (1 ..= from).rev() {
+            yield_!(n);
+        }
+        "Liftoff!"
+    }
+
+    #[test]
+    fn send ()
+    {
+        let generators: Vec<BoxGenerator<'static, u8, &str>> = vec![
+            countdown.call_boxed((2, )),
+            sources::from_iter(vec![7])
+                .map_return(|_| "from_iter")
+                .boxed()
+            ,
+        ];
+        let yielded =
+            ::std::thread::spawn(move || {
+                generators.into_iter().flatten().collect::<Vec<_>>()
+            })
+            .join()
+            .unwrap()
+        ;
+        assert_eq!(yielded, [2, 1, 7]);
+
+        let mut generator = countdown.call_boxed((1, )) as BoxGenerator<_, _>;
+        let by_ref: Pin<&mut DynGeneratorSend<'_, u8, &str>> =
+            generator.as_mut()
+        ;
+        assert_eq!(by_ref.collect::<Vec<_>>(), [1]);
+    }
+
+    #[test]
+    fn local ()
+    {
+        let shared = ::std::rc::Rc::new(42);
+        let mut generator: LocalBoxGenerator<'_, i32, ()> =
+            sources::from_fn(|()| match ::std::rc::Rc::strong_count(&shared) {
+                | 1 => GeneratorState::Yielded(*shared),
+                | _ => GeneratorState::Returned(()),
+            })
+            .boxed_local()
+        ;
+        assert_eq!(generator.next(), Some(42));
+        let _clone = shared.clone();
+        assert_eq!(generator.next(), None);
+    }
+
+    #[test]
+    fn resume_args ()
+    {
+        #[generator(yield(u8), resume(u8) as first)]
+        fn scaled (values: &'static [u8])
+        {
+            let mut ratio = first;
+            for &value in values {
+                ratio = yield_!(value * ratio);
+            }
+        }
+
+        let generator: BoxGenerator<'_, u8, (), u8> =
+            scaled.call_boxed((&[1, 2, 3][..], ))
+        ;
+        let mut fed = generator.feed(vec![10, 2, 1, 0]);
+        assert_eq!(fed.by_ref().collect::<Vec<_>>(), [10, 4, 3]);
+        assert_eq!(fed.take_return(), Some(()));
+    }
+
+    #[test]
+    fn higher_ranked_resume_args ()
+    {
+        type Words<'lt> = Pin<Box<
+            dyn 'lt + for<'s> Generator<&'s str, Yield = usize, Return = ()>
+        >>;
+
+        let count = |total: &mut usize, text: &str| match text {
+            | "" => GeneratorState::Returned(()),
+            | _ => {
+                *total += text.split_whitespace().count();
+                GeneratorState::Yielded(*total)
+            },
+        };
+        let mut words: Words<'_> = Box::pin(sources::unfold(0, count));
+        for (line, expected) in vec![("a b", 2), ("c", 3)] {
+            let line = String::from(line);
+            assert_eq!(
+                words.resume_unpin(&line),
+                GeneratorState::Yielded(expected),
+            );
+        }
+        let lines = [String::from("d e f")];
+        let mut fed = (&mut words).feed(lines.iter().map(String::as_str));
+        assert_eq!(fed.next(), Some(6));
+        assert_eq!(fed.next(), None);
+        assert_eq!(words.resume_unpin(""), GeneratorState::Returned(()));
+    }
+}
+
+mod scoped {
+    use super::{*, panic};
+    use crate::generator_fn::{GeneratorFn, internals::YieldSlot};
+
+    /// Would abort with `.init()`, but `YieldSlot` leaks are harmless with
+    /// `.init_scoped()`.
+    async fn leaky (yield_slot: YieldSlot<'_, u8, ()>, (): ())
+    {
+        yield_slot.__take_initial_arg();
+        yield_slot.__put(42).await;
+        mem::forget(yield_slot);
+    }
+
+    #[test]
+    fn leaked_yield_slot ()
+    {
+        let generator = GeneratorFn::empty();
+        stack_pinned!(mut generator);
+        generator.as_mut().init_scoped(leaky, ());
+        assert_eq!(generator.collect::<Vec<_>>(), [42]);
+    }
+
+    /// (Older compilers always go through `.init()`, see `build.rs`.)
+    #[cfg(next_gen_scoped_autoref)]
+    #[test]
+    fn mk_gen_uses_init_scoped ()
+    {
+        mk_gen!(let generator = leaky());
+        assert_eq!(generator.collect::<Vec<_>>(), [42]);
+        mk_gen!(let generator = box leaky());
+        assert_eq!(generator.into_iter().collect::<Vec<_>>(), [42]);
+    }
+
+    #[test]
+    fn mk_gen_falls_back_to_init ()
+    {
+        /// Its `'yield_slot` is early-bound, so it cannot be `.init_scoped()`.
+        async fn early_bound<'yield_slot> (
+            yield_slot: YieldSlot<'yield_slot, u8, ()>,
+            (): (),
+        )
+        where
+            u8 : 'yield_slot,
+        {
+            yield_slot.__take_initial_arg();
+            yield_slot.__put(42).await;
+        }
+
+        mk_gen!(let generator = early_bound());
+        assert_eq!(generator.collect::<Vec<_>>(), [42]);
+        mk_gen!(let generator = box early_bound());
+        assert_eq!(generator.into_iter().collect::<Vec<_>>(), [42]);
+    }
+}
+
+#[cfg(feature = "hardened")]
+mod hardened {
+    use super::{*, panic};
+    use crate::generator_fn::{
+        GeneratorFnState,
+        Misusage,
+        ResumeError,
+        internals::YieldSlot,
+    };
+
+    #[test]
+    fn slot_not_empty ()
+    {
+        async fn double_put (yield_slot: YieldSlot<'_, u8>, (): ())
+        {
+            yield_slot.__take_initial_arg();
+            let _not_awaited = yield_slot.__put(1);
+            yield_slot.__put(2).await;
+        }
+
+        mk_gen!(let mut generator = double_put());
+        assert_eq!(
+            generator.as_mut().try_resume(()),
+            Err(ResumeError::Misusage(Misusage::SlotNotEmpty)),
+        );
+        assert_eq!(generator.state(), GeneratorFnState::Poisoned);
+        assert_eq!(
+            generator.as_mut().try_resume(()),
+            Err(ResumeError::Poisoned),
+        );
+    }
+
+    #[test]
+    fn missing_yielded_item ()
+    {
+        async fn suspends (yield_slot: YieldSlot<'_, u8>, (): ())
+        {
+            yield_slot.__take_initial_arg();
+            poll_fn(|_| Poll::<()>::Pending).await;
+        }
+
+        mk_gen!(let mut generator = suspends());
+        assert_eq!(
+            generator.as_mut().try_resume(()),
+            Err(ResumeError::Misusage(Misusage::MissingYieldedItem)),
+        );
+        assert_eq!(generator.state(), GeneratorFnState::Poisoned);
+    }
+
+    #[test]
+    fn unused_resume_arg ()
+    {
+        async fn awaits (_: YieldSlot<'_, u8>, (): ())
+        {
+            poll_fn(|_| Poll::<()>::Pending).await;
+        }
+
+        mk_gen!(let mut generator = awaits());
+        crate::utils::macros::create_context!(cx);
+        assert_eq!(
+            generator.as_mut().try_poll_resume(&mut cx),
+            Poll::Ready(Err(ResumeError::Misusage(Misusage::UnusedResumeArg))),
+        );
+        assert_eq!(generator.state(), GeneratorFnState::Poisoned);
+    }
+}
+
+mod proc_macros {
+    use super::{*, panic};
+    use ::next_gen_proc_macros::generator;
+
+    #[test]
+    fn resume_args ()
+    {
+        #[generator(yield(u8), resume(i32) as mut arg)]
+        fn generator<'foo> ()
+          -> Vec<i32>
+        {
+            let mut resume_args = vec![];
+            while arg != 0 {
+                resume_args.push(arg);
+                arg = yield_!(arg as _);
+            }
+
+            resume_args
+        }
+
+        mk_gen!(let mut generator = generator());
+        let mut resume = |arg| match generator.as_mut().resume(arg) {
+            | GeneratorState::Yielded(yielded_value) => {
+                assert_eq!(yielded_value as i32, arg);
+                None
+            },
+            | GeneratorState::Returned(ret) => {
+                assert_eq!(arg, 0);
+                Some(ret)
+            },
+        };
+
+        resume(12);
+        resume(17);
+        resume(47);
+        assert_eq!(resume(0).unwrap(), vec![12, 17, 47]);
+    }
+
+    #[test]
+    fn range ()
+    {
+        #[generator(yield(u8))]
+        fn range (start: u8, end: u8)
+        {
+            let mut current = start;
+            while current < end {
+                yield_!(current);
+                current += 1;
+            }
+        }
+
+        mk_gen!(let generator = range(2, 8));
+        assert_it_eq!(
+            generator,
+            Vec::from_iter(2 .. 8),
+        );
+    }
+
+    #[test]
+    fn gen_iter ()
+    {
+        type Question = &'static str;
+        type Answer = i32;
+
+        #[generator(yield(Question))]
+        fn answer ()
+          -> Answer
+        {
+            yield_!("What is the answer to life, the universe and everything?");
+            42
+        }
+
+        let ret = gen_iter!(
+            for question in answer() {
+                assert_eq!(
+                    question,
+                    "What is the answer to life, the universe and everything?",
+                );
+            }
+        );
+        assert_eq!(ret, 42);
+    }
+
+    mod adaptors {
+        use super::{*, panic};
+
+        #[generator(yield(T))]
+        fn filter<T> (
+            mut predicate: impl FnMut(&T) -> bool,
+            iterable: impl IntoIterator<Item = T>,
+        )
+        {
+            for element in iterable {
+                if predicate(&element) {
+                    yield_!(element);
+                }
+            }
+        }
+
+        #[generator(yield(U))]
+        fn map<T, U> (
+            mut f: impl FnMut(T) -> U,
+            iterable: impl IntoIterator<Item = T>,
+        )
+        {
+            for element in iterable {
+                yield_!(f(element));
+            }
+        }
+
+        #[generator(yield(u8))]
+        fn range (start: u8, end: u8)
+        {
+            let mut current = start;
+            while current < end {
+                yield_!(current);
+                current += 1;
+            }
+        }
+
+        #[test]
+        fn filter_range ()
+        {
+
+            mk_gen!(let iterator = range(2, 7));
+            mk_gen!(let iterator = filter(|x| x % 2 == 0, iterator));
+            assert_it_eq!(
+                iterator,
+                [2, 4, 6],
+            );
+        }
+
+        #[test]
+        fn filter_map_range ()
+        {
+
+            mk_gen!(let iterator = range(2, 7));
+            mk_gen!(let iterator = filter(|x| x % 2 == 0, iterator));
+            mk_gen!(let iterator = map(|x| x * x, iterator));
+            assert_it_eq!(
+                iterator,
+                [4, 16, 36],
+            );
+        }
+    }
+
+    mod yield_from {
+        use super::{*, panic};
+        use crate::generator::Iterable;
+
+        #[generator(yield(u8), resume(bool) as mut keep_going)]
+        fn count_up (start: u8)
+          -> u8
+        {
+            let mut current = start;
+            while keep_going {
+                keep_going = yield_!(current);
+                current += 1;
+            }
+            current
+        }
+
+        #[test]
+        fn sub_generator ()
+        {
+            #[generator(yield(u8), resume(bool))]
+            fn parent ()
+              -> u8
+            {
+                let a = yield_from!(count_up(10), true);
+                let b = yield_from!(count_up(20), false);
+                a + b
+            }
+
+            mk_gen!(let mut generator = parent());
+            let mut resume = |arg| generator.as_mut().resume(arg);
+            assert_eq!(resume(false), GeneratorState::Yielded(10));
+            assert_eq!(resume(true), GeneratorState::Yielded(11));
+            // `false` is forwarded to the child, which returns,
+            // and then the given `false` starts the second one.
+            assert_eq!(resume(false), GeneratorState::Returned(12 + 20));
+        }
+
+        #[test]
+        fn iterable ()
+        {
+            #[generator(yield(u8))]
+            fn chain (a: u8, bs: Vec<u8>)
+            {
+                yield_!(a);
+                let bs = Iterable(bs);
+                yield_from!(bs);
+                yield_from!((Iterable((1 ..= 2).map(|x| 10 * x))));
+            }
+
+            mk_gen!(let generator = chain(0, vec![1, 2]));
+            assert_it_eq!(generator, [0, 1, 2, 10, 20]);
+        }
+
+        #[test]
+        fn iterable_from_a_call ()
+        {
+            fn tens ()
+              -> impl Iterator<Item = u8>
+            {
+                (1 ..= 2).map(|x| 10 * x)
+            }
+
+            #[generator(yield(u8))]
+            fn chain ()
+            {
+                yield_from!((Iterable(tens())));
+            }
+
+            mk_gen!(let generator = chain());
+            assert_it_eq!(generator, [10, 20]);
+        }
+    }
+
+    mod closable {
+        use super::{*, panic};
+        use ::core::cell::RefCell;
+        use crate::{
+            generator::Iterable,
+            generator_fn::{CloseError, Closed, GeneratorFnState},
+        };
+
+        #[generator(yield(u8), resume(u8) as mut step, closable)]
+        fn counter (log: &'_ RefCell<Vec<&'static str>>)
+          -> u8
+        {
+            let mut current = 0;
+            loop {
+                current += step;
+                match yield_!(current) {
+                    | Ok(next_step) => step = next_step,
+                    | Err(Closed) => {
+                        log.borrow_mut().push("cleanup");
+                        return current;
+                    },
+                }
+            }
+        }
+
+        #[test]
+        fn cleanup ()
+        {
+            let log = RefCell::new(vec![]);
+            mk_gen!(let mut generator = counter(&log));
+            assert_eq!(generator.as_mut().resume(1), GeneratorState::Yielded(1));
+            assert_eq!(generator.as_mut().resume(2), GeneratorState::Yielded(3));
+            assert_eq!(generator.as_mut().close(), Ok(Some(3)));
+            assert_eq!(*log.borrow(), ["cleanup"]);
+            assert_eq!(generator.state(), GeneratorFnState::Completed);
+            assert_eq!(generator.as_mut().close(), Ok(None));
+        }
+
+        #[test]
+        fn unstarted ()
+        {
+            let log = RefCell::new(vec![]);
+            mk_gen!(let mut generator = counter(&log));
+            assert_eq!(generator.as_mut().close(), Ok(None));
+            assert!(log.borrow().is_empty());
+            assert_eq!(generator.state(), GeneratorFnState::Completed);
+        }
+
+        #[test]
+        fn ignored ()
+        {
+            #[generator(yield(&'static str), closable)]
+            fn stubborn ()
+            {
+                while yield_!("again").is_err() {}
+            }
+
+            mk_gen!(let mut generator = stubborn());
+            assert_eq!(generator.as_mut().resume(()), GeneratorState::Yielded("again"));
+            assert_eq!(generator.as_mut().close(), Err(CloseError::Ignored("again")));
+            assert_eq!(generator.state(), GeneratorFnState::Suspended);
+            assert_eq!(generator.as_mut().resume(()), GeneratorState::Returned(()));
+        }
+
+        #[test]
+        fn not_closable ()
+        {
+            let dropped = RefCell::new(false);
+            #[generator(yield(u8))]
+            fn forever (dropped: &'_ RefCell<bool>)
+            {
+                struct OnDrop<'r>(&'r RefCell<bool>);
+                impl Drop for OnDrop<'_> {
+                    fn drop (self: &'_ mut Self)
+                    {
+                        *self.0.borrow_mut() = true;
+                    }
+                }
+                let _guard = OnDrop(dropped);
+                loop {
+                    yield_!(0);
+                }
+            }
+
+            mk_gen!(let mut generator = forever(&dropped));
+            assert_eq!(generator.as_mut().resume(()), GeneratorState::Yielded(0));
+            assert_eq!(generator.as_mut().close(), Ok(None));
+            assert!(*dropped.borrow());
+            assert_eq!(generator.state(), GeneratorFnState::Completed);
+        }
+
+        #[test]
+        fn yield_from_is_not_closable ()
+        {
+            #[generator(yield(u8), closable)]
+            fn delegating ()
+              -> bool
+            {
+                yield_from!((Iterable(0 .. 2)), ());
+                yield_!(2).is_err()
+            }
+
+            mk_gen!(let mut generator = delegating());
+            assert_eq!(generator.as_mut().resume(()), GeneratorState::Yielded(0));
+            assert_eq!(generator.as_mut().close(), Ok(None));
+
+            mk_gen!(let mut generator = delegating());
+            let _ = generator.as_mut().resume(());
+            let _ = generator.as_mut().resume(());
+            assert_eq!(generator.as_mut().resume(()), GeneratorState::Yielded(2));
+            assert_eq!(generator.as_mut().close(), Ok(Some(true)));
+        }
+    }
+
+    mod throws {
+        use super::{*, panic};
+        use crate::{
+            generator::GeneratorExt as _,
+            generator_fn::CallBoxed as _,
+        };
+
+        #[derive(Debug, PartialEq)]
+        struct Garbage(u8);
+
+        #[derive(Debug, PartialEq)]
+        enum ProtocolError {
+            Garbage(u8),
+            Overflow,
+        }
+
+        impl From<Garbage> for ProtocolError {
+            fn from (Garbage(byte): Garbage)
+              -> ProtocolError
+            {
+                ProtocolError::Garbage(byte)
+            }
+        }
+
+        /// Expects `n` bytes, and then yields their sum.
+        #[generator(yield(()), resume(u8) as first, throws(Garbage))]
+        fn checksum (n: usize)
+          -> Result<u8, ProtocolError>
+        {
+            let mut sum = first;
+            for _ in 1 .. n {
+                let byte = yield_!(())?;
+                sum = sum.checked_add(byte).ok_or(ProtocolError::Overflow)?;
+            }
+            Ok(sum)
+        }
+
+        #[test]
+        fn not_thrown ()
+        {
+            mk_gen!(let mut generator = checksum(3));
+            assert_eq!(generator.as_mut().resume(Ok(1)), GeneratorState::Yielded(()));
+            assert_eq!(generator.as_mut().resume(Ok(2)), GeneratorState::Yielded(()));
+            assert_eq!(
+                generator.as_mut().resume(Ok(3)),
+                GeneratorState::Returned(Ok(6)),
+            );
+        }
+
+        #[test]
+        fn thrown ()
+        {
+            mk_gen!(let mut generator = checksum(3));
+            assert_eq!(generator.as_mut().resume(Ok(1)), GeneratorState::Yielded(()));
+            assert_eq!(
+                generator.as_mut().throw(Garbage(42)),
+                GeneratorState::Returned(Err(ProtocolError::Garbage(42))),
+            );
+        }
+
+        #[test]
+        fn thrown_before_start ()
+        {
+            mk_gen!(let mut generator = checksum(3));
+            assert_eq!(
+                generator.as_mut().throw(Garbage(0)),
+                GeneratorState::Returned(Err(ProtocolError::Garbage(0))),
+            );
+        }
+
+        #[test]
+        fn caught ()
+        {
+            /// Skips the garbage.
+            #[generator(yield(u8), throws(&'static str))]
+            fn resilient ()
+              -> Result<(), &'static str>
+            {
+                let mut errors = 0;
+                while errors < 2 {
+                    if yield_!(errors).is_err() {
+                        errors += 1;
+                    }
+                }
+                Ok(())
+            }
+
+            let mut generator = resilient.call_boxed(());
+            assert_eq!(generator.as_mut().resume(Ok(())), GeneratorState::Yielded(0));
+            assert_eq!(generator.as_mut().throw("oops"), GeneratorState::Yielded(1));
+            assert_eq!(generator.as_mut().resume(Ok(())), GeneratorState::Yielded(1));
+            assert_eq!(
+                generator.as_mut().throw("oops"),
+                GeneratorState::Returned(Ok(())),
+            );
+        }
+
+        #[test]
+        fn coroutine_sugar ()
+        {
+            #[crate::coroutine(u8, throws(Garbage))]
+            fn echo (arg @ yield_: u8)
+              -> Result<(), Garbage>
+            {
+                let mut arg = arg;
+                loop {
+                    arg = yield_!(arg)?;
+                }
+            }
+
+            mk_coro!(let mut coroutine = echo());
+            assert_eq!(coroutine.as_mut().resume(Ok(7)), GeneratorState::Yielded(7));
+            assert_eq!(
+                coroutine.as_mut().throw(Garbage(7)),
+                GeneratorState::Returned(Err(Garbage(7))),
+            );
+        }
+    }
+
+    mod send {
+        use super::{*, panic};
+        use crate::generator_fn::{CallBoxed as _, GeneratorFn};
+
+        fn is_send<T : Send> (it: T)
+          -> T
+        {
+            it
+        }
+
+        fn is_sync<T : ?Sized + Sync> (_: &'_ T)
+        {}
+
+        #[generator(yield(u32), send)]
+        fn numbers (count: u32)
+          -> Result<u32, ()>
+        {
+            for i in 0 .. count {
+                yield_!(i);
+            }
+            let n = Some(count).ok_or(())?;
+            Ok(n)
+        }
+
+        #[test]
+        fn across_threads ()
+        {
+            let mut generator = is_send(numbers.call_boxed((2, )));
+            ::std::thread::spawn(move || {
+                let mut next = || generator.as_mut().resume(());
+                assert_eq!(next(), GeneratorState::Yielded(0));
+                assert_eq!(next(), GeneratorState::Yielded(1));
+                assert_eq!(next(), GeneratorState::Returned(Ok(2)));
+            }).join().unwrap();
+        }
+
+        #[test]
+        fn sync ()
+        {
+            #[generator(yield(u8))]
+            fn not_sync ()
+            {
+                let cell = ::core::cell::Cell::new(42);
+                yield_!(cell.get());
+                cell.set(0);
+            }
+
+            mk_gen!(let generator = not_sync());
+            is_sync::<GeneratorFn<_, _, _>>(&*generator);
+        }
+
+        #[test]
+        fn boxed_and_methods ()
+        {
+            #[generator(yield(u8), boxed, send)]
+            fn boxed_countdown (from: u8)
+            {
+                for i in (1 ..= from).rev() {
+                    yield_!(i);
+                }
+            }
+
+            struct Countdown(u8);
+            impl Countdown {
+                #[generator(yield(u8), send)]
+                fn iter (&self)
+                {
+                    for i in (1 ..= self.0).rev() {
+                        yield_!(i);
+                    }
+                }
+            }
+
+            let generator = is_send(boxed_countdown(2));
+            assert_eq!(generator.collect::<Vec<_>>(), [2, 1]);
+            let countdown = Countdown(2);
+            mk_gen!(let generator = box countdown.iter());
+            let generator = is_send(generator);
+            assert_eq!(generator.collect::<Vec<_>>(), [2, 1]);
+            let evens = gen!(yield(u8), send => {
+                yield_!(0);
+                yield_!(2);
+            });
+            mk_gen!(let generator = box evens());
+            let generator = is_send(generator);
+            assert_eq!(generator.collect::<Vec<_>>(), [0, 2]);
+        }
+    }
+
+    mod expressions {
+        use super::{*, panic};
+        use ::next_gen_proc_macros::gen;
+        use crate::{generator::Iterable, generator_fn::CallBoxed as _};
+
+        #[test]
+        fn gen_block ()
+        {
+            let mut seen = vec![];
+            let start = 2;
+            let numbers = gen!(yield(u8), resume(bool) as mut keep_going => {
+                let mut current = start;
+                while keep_going {
+                    seen.push(current);
+                    keep_going = yield_!(current);
+                    current += 1;
+                }
+                current
+            });
+            {
+                mk_gen!(let mut generator = numbers());
+                let mut resume = |arg| generator.as_mut().resume(arg);
+                assert_eq!(resume(true), GeneratorState::Yielded(2));
+                assert_eq!(resume(true), GeneratorState::Yielded(3));
+                assert_eq!(resume(false), GeneratorState::Returned(4));
+            }
+            assert_eq!(seen, [2, 3]);
+        }
+
+        #[test]
+        fn gen_move_block ()
+        {
+            let elems = vec![1, 2, 3];
+            let generator = gen!(yield(i32) => move {
+                yield_from!((Iterable(elems)));
+                Ok::<_, ()>(())
+            }).call_boxed(());
+            assert_it_eq!(generator, [1, 2, 3]);
+            assert_eq!(
+                gen_iter!(for _ in (gen!(yield(()) => { Err(())?; Ok(()) }))() {}),
+                Err(()),
+            );
+        }
+    }
+
+    #[cfg(feature = "nightly")]
+    mod closures {
+        use super::{*, panic};
+        use crate::generator_fn::CallBoxed as _;
+
+        #[test]
+        fn captures ()
+        {
+            let mut seen = vec![];
+            let offset = 10;
+            {
+                let numbers = #[generator(yield(u8))] |from: u8, to: u8| {
+                    for i in from .. to {
+                        seen.push(i);
+                        yield_!(offset + i);
+                    }
+                };
+                mk_gen!(let generator = numbers(1, 3));
+                assert_it_eq!(generator, [11, 12]);
+            }
+            assert_eq!(seen, [1, 2]);
+        }
+
+        #[test]
+        fn move_ ()
+        {
+            let elems = vec![1, 2, 3];
+            let generator = #[generator(yield(i32))] move || {
+                for elem in elems {
+                    yield_!(elem);
+                }
+            };
+            let generator = generator.call_boxed(());
+            let yielded =
+                ::std::thread::spawn(move || {
+                    generator.collect::<Vec<_>>()
+                })
+                .join()
+                .unwrap()
+            ;
+            assert_eq!(yielded, [1, 2, 3]);
+        }
+
+        #[test]
+        fn resume_args_and_return ()
+        {
+            let summer =
+                #[generator(yield(u32), resume(u32) as mut x)]
+                |(start, factor): (u32, u32)| -> u32 {
+                    let mut sum = start;
+                    while x != 0 {
+                        sum += factor * x;
+                        x = yield_!(sum);
+                    }
+                    sum
+                }
+            ;
+            mk_gen!(let mut generator = summer((100, 2)));
+            let mut resume = |arg| generator.as_mut().resume(arg);
+            assert_eq!(resume(1), GeneratorState::Yielded(102));
+            assert_eq!(resume(2), GeneratorState::Yielded(106));
+            assert_eq!(resume(0), GeneratorState::Returned(106));
+
+            let countdown = #[generator(yield(u8))] |from: u8| {
+                for i in (1 ..= from).rev() {
+                    yield_!(i);
+                }
+                "Liftoff!"
+            };
+            let mut yielded = vec![];
+            let returned = gen_iter!(for i in countdown(2) {
+                yielded.push(i);
+            });
+            assert_eq!(yielded, [2, 1]);
+            assert_eq!(returned, "Liftoff!");
+        }
+    }
+
+    mod methods {
+        use super::{*, panic};
+        use crate::generator_fn::GeneratorMethodCall as _;
+
+        struct Counter<T> {
+            elems: Vec<T>,
+            count: usize,
+        }
+
+        impl<T : Clone> Counter<T> {
+            #[generator(yield(&T))]
+            fn iter (&self, skip: usize)
+              -> usize
+            {
+                for elem in &self.elems[skip ..] {
+                    yield_!(elem);
+                }
+                self.elems.len() - skip
+            }
+
+            #[generator(yield(T), resume(bool) as mut keep_going)]
+            fn count (&mut self, prefix: &[T])
+            {
+                for elem in prefix.iter().chain(&self.elems) {
+                    if keep_going.not() {
+                        break;
+                    }
+                    self.count += 1;
+                    keep_going = yield_!(elem.clone());
+                }
+            }
+
+            #[generator(yield(T))]
+            fn into_elems (mut self)
+              -> Vec<T>
+            {
+                while let Some(elem) = self.elems.pop() {
+                    yield_!(elem);
+                }
+                self.elems
+            }
+        }
+
+        #[test]
+        fn shared_receiver ()
+        {
+            let counter = Counter { elems: vec![1, 2, 3], count: 0 };
+            let ret = gen_iter!(for _ in counter.iter(1) {});
+            assert_eq!(ret, 2);
+            mk_gen!(let iter = counter.iter(0));
+            assert_it_eq!(iter, [&1, &2, &3]);
+            assert_it_eq!(counter.iter(2).call_boxed(), [&3]);
+        }
+
+        #[test]
+        fn unique_receiver ()
+        {
+            let mut counter = Counter { elems: vec![1, 2, 3], count: 0 };
+            {
+                mk_gen!(let mut gen = counter.count(&[0]));
+                assert_eq!(gen.as_mut().resume(true), GeneratorState::Yielded(0));
+                assert_eq!(gen.as_mut().resume(true), GeneratorState::Yielded(1));
+                assert_eq!(gen.as_mut().resume(false), GeneratorState::Complete);
+            }
+            assert_eq!(counter.count, 2);
+        }
+
+        #[test]
+        fn owned_receiver ()
+        {
+            let counter = Counter { elems: vec![1, 2, 3], count: 0 };
+            let ret = gen_iter!(for _ in counter.into_elems() {});
+            assert_eq!(ret, []);
+            let counter = Counter { elems: vec![1, 2, 3], count: 0 };
+            mk_gen!(let gen = box counter.into_elems());
+            assert_it_eq!(gen, [3, 2, 1]);
+        }
+
+        #[test]
+        fn nested_items_keep_their_self ()
+        {
+            struct Wrapper(u8);
+            impl Wrapper {
+                #[generator(yield(u8))]
+                fn each (&self)
+                {
+                    struct Doubled(u8);
+                    impl Doubled {
+                        fn get (self: &'_ Self)
+                          -> u8
+                        {
+                            2 * self.0
+                        }
+                    }
+                    let plus_one = || self.0 + 1;
+                    yield_!(self.0);
+                    yield_!(plus_one());
+                    yield_!(Doubled(self.0).get());
+                    yield_!(vec![self.0][0]);
+                }
+            }
+            let wrapper = Wrapper(5);
+            mk_gen!(let generator = wrapper.each());
+            assert_it_eq!(generator, [5, 6, 10, 5]);
+        }
+    }
+
+    #[test]
+    fn return_iterator_with_concrete_dyn_type ()
+    {
+        enum Void {}
+        type None = Option<Void>;
+
+        trait Countdown {
+            type Iter : Iterator<Item = u8>;
+            fn countdown (self: &'_ Self)
+              -> Self::Iter
+            ;
+        }
+
+        struct CountdownFrom(u8);
+        impl Countdown for CountdownFrom {
+            type Iter = Pin<Box<
+                dyn Generator<(), Yield = u8, Return = None>
+            >>;
+
+            fn countdown (self: &'_ CountdownFrom)
+              -> Pin<Box<
+                    dyn Generator<(), Yield = u8, Return = None>
+                >>
+            {
+                #[generator(yield(u8))]
+                fn countdown (from: u8)
+                  -> None
+                {
+                    let mut current = from;
+                    loop {
+                        yield_!(current);
+                        current = current.checked_sub(1)?;
+                    }
+                }
+                mk_gen!(let countdown = box countdown(self.0));
+                countdown
+            }
+        }
+        assert_it_eq!(
+            CountdownFrom(3).countdown(),
+            [3, 2, 1, 0],
+        );
+    }
+
+    #[test]
+    fn nested_bodies ()
+    {
+        #[generator(yield(u8))]
+        fn outer ()
+          -> Vec<u8>
+        {
+            #[generator(yield(u8))]
+            fn inner ()
+            {
+                yield_!(1);
+            }
+            let more = gen!(yield(u8) => {
+                yield_!(2);
+            });
+            let _unpolled = async {
+                async {}.await;
+            };
+            let resumed = vec![
+                yield_!(0),
+                yield_from!(inner()),
+                yield_from!(more()),
+            ];
+            gen_iter!(for x in inner() {
+                yield_!(x + 2);
+            });
+            resumed.into_iter().map(|()| 42).collect()
+        }
+        let mut yielded = vec![];
+        let ret = gen_iter!(for x in outer() {
+            yielded.push(x);
+        });
+        assert_eq!(yielded, [0, 1, 2, 3]);
+        assert_eq!(ret, [42, 42, 42]);
+    }
+
+    mod async_generators {
+        use super::{*, panic};
+        use ::std::{sync::atomic::{AtomicUsize, Ordering}, task};
+
+        /// Pending once, waking the task right away.
+        struct YieldNow(bool);
+        impl Future for YieldNow {
+            type Output = ();
+
+            fn poll (mut self: Pin<&'_ mut Self>, cx: &'_ mut task::Context<'_>)
+              -> task::Poll<()>
+            {
+                if mem::replace(&mut self.0, true) {
+                    task::Poll::Ready(())
+                } else {
+                    cx.waker().wake_by_ref();
+                    task::Poll::Pending
+                }
+            }
+        }
+
+        /// A waker counting its wake-ups into the `AtomicUsize` its data
+        /// points to (which must thus outlive it).
+        fn count_wakes (wakes: &'_ AtomicUsize)
+          -> task::Waker
+        {
+            const VTABLE: task::RawWakerVTable = {
+                unsafe
+                fn clone (data: *const ())
+                  -> task::RawWaker
+                {
+                    task::RawWaker::new(data, &VTABLE)
+                }
+
+                unsafe
+                fn wake_by_ref (data: *const ())
+                {
+                    let wakes = &*data.cast::<AtomicUsize>();
+                    wakes.fetch_add(1, Ordering::SeqCst);
+                }
+
+                unsafe
+                fn drop (_: *const ())
+                {}
+
+                task::RawWakerVTable::new(clone, wake_by_ref, wake_by_ref, drop)
+            };
+
+            unsafe {
+                // Safety: the wakers do not outlive `poll_all()`'s counter.
+                task::Waker::from_raw(task::RawWaker::new(
+                    (wakes as *const AtomicUsize).cast(),
+                    &VTABLE,
+                ))
+            }
+        }
+
+        /// Polls the generator until completion, recording the (`None` for
+        /// `Pending`) states along the way.
+        fn poll_all<G : ?Sized + AsyncGenerator> (mut generator: Pin<&'_ mut G>)
+          -> (Vec<Option<G::Yield>>, G::Return, usize)
+        {
+            let wakes = AtomicUsize::new(0);
+            let waker = count_wakes(&wakes);
+            let mut cx = task::Context::from_waker(&waker);
+            let mut states = vec![];
+            loop {
+                match generator.as_mut().poll_resume(&mut cx) {
+                    | task::Poll::Pending => states.push(None),
+                    | task::Poll::Ready(GeneratorState::Yielded(it)) => {
+                        states.push(Some(it));
+                    },
+                    | task::Poll::Ready(GeneratorState::Returned(ret)) => {
+                        return (states, ret, wakes.load(Ordering::SeqCst));
+                    },
+                }
+            }
+        }
+
+        #[generator(yield(u8), async)]
+        fn numbers (count: u8)
+          -> &'static str
+        {
+            for i in 0 .. count {
+                YieldNow(false).await;
+                yield_!(i);
+            }
+            YieldNow(false).await;
+            "done"
+        }
+
+        #[test]
+        fn awaits ()
+        {
+            mk_gen!(let generator = numbers(2));
+            assert_eq!(
+                poll_all(generator),
+                (vec![None, Some(0), None, Some(1), None], "done", 3),
+            );
+        }
+
+        #[test]
+        fn method_and_expression ()
+        {
+            struct Numbers { count: u8 }
+            impl Numbers {
+                #[generator(yield(u8), async)]
+                fn each (&self)
+                {
+                    for i in 0 .. self.count {
+                        YieldNow(false).await;
+                        yield_!(i);
+                    }
+                }
+            }
+            let numbers = Numbers { count: 2 };
+            mk_gen!(let generator = numbers.each());
+            assert_eq!(
+                poll_all(generator),
+                (vec![None, Some(0), None, Some(1)], (), 2),
+            );
+
+            let start = 5;
+            let numbers = gen!(yield(u8), async => {
+                YieldNow(false).await;
+                yield_!(start);
+            });
+            mk_gen!(let generator = numbers());
+            assert_eq!(poll_all(generator), (vec![None, Some(5)], (), 1));
+        }
+
+        #[test]
+        fn boxed ()
+        {
+            #[generator(yield(u8), async, boxed)]
+            fn evens (count: u8)
+            {
+                for i in 0 .. count {
+                    if i % 2 == 0 {
+                        yield_!(i);
+                    } else {
+                        YieldNow(false).await;
+                    }
+                }
+            }
+            let (states, (), wakes) = poll_all(evens(4).as_mut());
+            assert_eq!(states, [Some(0), None, Some(2), None]);
+            assert_eq!(wakes, 2);
+        }
+
+        #[cfg(feature = "futures-core")]
+        #[test]
+        fn stream ()
+        {
+            use ::futures_core::Stream;
+
+            let wakes = AtomicUsize::new(0);
+            let waker = count_wakes(&wakes);
+            let mut cx = task::Context::from_waker(&waker);
+            mk_gen!(let mut generator = numbers(1));
+            let mut next = || generator.as_mut().poll_next(&mut cx);
+            assert_eq!(next(), task::Poll::Pending);
+            assert_eq!(next(), task::Poll::Ready(Some(0)));
+            assert_eq!(next(), task::Poll::Pending);
+            assert_eq!(next(), task::Poll::Ready(None));
+        }
+    }
+
+    mod boxed {
+        use super::{*, panic};
+
+        enum Void {}
+        type None = Option<Void>;
+
+        #[generator(yield(u8), boxed)]
+        fn countdown (from: u8)
+          -> None
+        {
+            let mut current = from;
+            loop {
+                yield_!(current);
+                current = current.checked_sub(1)?;
+            }
+        }
+
+        #[test]
+        fn free_fn ()
+        {
+            assert_eq!(countdown(3).collect::<Vec<_>>(), [3, 2, 1, 0]);
+        }
+
+        #[test]
+        fn trait_method ()
+        {
+            trait Countdown {
+                fn countdown (self: &'_ Self)
+                  -> Pin<Box<dyn '_ + Generator<(), Yield = u8, Return = None>>>
+                ;
+            }
+
+            struct CountdownFrom(u8);
+            impl Countdown for CountdownFrom {
+                #[generator(yield(u8), boxed)]
+                fn countdown (&self)
+                  -> None
+                {
+                    let mut current = self.0;
+                    loop {
+                        yield_!(current);
+                        current = current.checked_sub(1)?;
+                    }
+                }
+            }
+            assert_it_eq!(
+                CountdownFrom(3).countdown(),
+                [3, 2, 1, 0],
+            );
+        }
+
+        #[test]
+        fn borrowing_generic_args ()
+        {
+            #[generator(yield(&T), resume(bool) as mut keep_going, boxed)]
+            fn iter<T> (elems: &[T], start: usize)
+              -> usize
+            {
+                let mut count = 0;
+                for elem in &elems[start ..] {
+                    if keep_going.not() {
+                        break;
+                    }
+                    keep_going = yield_!(elem);
+                    count += 1;
+                }
+                count
+            }
+            let elems = vec![1, 2, 3];
+            let mut gen = iter(&elems, 1);
+            assert_eq!(gen.as_mut().resume(true), GeneratorState::Yielded(&2));
+            assert_eq!(gen.as_mut().resume(false), GeneratorState::Returned(1));
+        }
+
+        #[test]
+        fn send ()
+        {
+            #[generator(yield(String), boxed_send)]
+            fn words (line: String)
+            {
+                for word in line.split_whitespace() {
+                    yield_!(word.to_owned());
+                }
+            }
+            let words = words("Hello, World!".into());
+            let words = thread::spawn(|| words.collect::<Vec<_>>()).join();
+            assert_eq!(words.unwrap(), ["Hello,", "World!"]);
+        }
+
+        /// The statements of the body are spliced as is within the lowered
+        /// one, rather than as a nested block (which `unused_braces` would
+        /// flag, for one-line single-expression bodies).
+        #[test]
+        fn body_statements ()
+        {
+            #[deny(unused_braces)]
+            #[generator(yield(u8), boxed)]
+            fn single_expression (n: u8) -> u8 { n }
+
+            #[generator(yield(u8), resume(u8) as n, boxed)]
+            fn items_and_shadowing (factor: u8)
+              -> u8
+            {
+                const OFFSET: u8 = 1;
+                fn scale (n: u8, factor: u8)
+                  -> u8
+                {
+                    n * factor + OFFSET
+                }
+                let n = scale(n, factor);
+                let n = yield_!(n);
+                scale(n, factor)
+            }
+
+            let mut gen = single_expression(42);
+            assert_eq!(gen.as_mut().resume(()), GeneratorState::Returned(42));
+            let mut gen = items_and_shadowing(10);
+            assert_eq!(gen.as_mut().resume(1), GeneratorState::Yielded(11));
+            assert_eq!(gen.as_mut().resume(2), GeneratorState::Returned(21));
+        }
+    }
+
+    mod coroutines {
+        use super::{*, panic};
+        use crate::coroutine::Coroutine;
+
+        /// Resumes the coroutine with each of the `args`, collecting what it
+        /// yields, until it returns.
+        fn drive<C : ?Sized + Coroutine> (
+            mut coroutine: Pin<&'_ mut C>,
+            args: impl IntoIterator<Item = C::ResumeArg>,
+        ) -> (Vec<C::Yield>, Option<C::Return>)
+        {
+            let mut yielded = vec![];
+            for arg in args {
+                match coroutine.as_mut().resume_with(arg) {
+                    | GeneratorState::Yielded(it) => yielded.push(it),
+                    | GeneratorState::Returned(ret) => {
+                        return (yielded, Some(ret));
+                    },
+                }
+            }
+            (yielded, None)
+        }
+
+        #[test]
+        fn resume_arg_binding ()
+        {
+            #[crate::coroutine(String)]
+            fn greeter (greeting: &'static str, mut name @ yield_: &'static str)
+              -> usize
+            {
+                let mut count = 0;
+                while name.is_empty().not() {
+                    count += 1;
+                    name = yield_!(format!("{}, {}!", greeting, name));
+                }
+                count
+            }
+
+            mk_coro!(let greeter = greeter("Hello"));
+            assert_eq!(
+                drive(greeter, vec!["Alice", "Bob", ""]),
+                (vec!["Hello, Alice!".into(), "Hello, Bob!".into()], Some(2)),
+            );
+        }
+
+        #[test]
+        fn ignored_initial_arg ()
+        {
+            #[crate::coroutine(u8, boxed)]
+            fn echo (yield_: u8)
+            {
+                let mut arg = yield_!(0);
+                while arg != 0 {
+                    arg = yield_!(arg);
+                }
+            }
+
+            let mut echo = echo();
+            assert_eq!(
+                drive(echo.as_mut(), vec![42, 1, 2, 0]),
+                (vec![0, 1, 2], Some(())),
+            );
+        }
+
+        #[test]
+        fn methods ()
+        {
+            struct Accumulator(u32);
+
+            impl Accumulator {
+                #[crate::coroutine(u32)]
+                fn accumulate (&mut self, mut x @ yield_: u32)
+                {
+                    while x != 0 {
+                        self.0 += x;
+                        x = yield_!(self.0);
+                    }
+                }
+            }
+
+            let mut acc = Accumulator(0);
+            {
+                mk_coro!(let coroutine = acc.accumulate());
+                assert_eq!(drive(coroutine, vec![1, 2, 3, 0]).0, [1, 3, 6]);
+            }
+            assert_eq!(acc.0, 6);
+        }
+    }
 }
 
 
@@ -0,0 +1,142 @@
+// Not a glob import, lest the `coroutine` attribute clash with the built-in
+// one.
+use {
+    ::core::ops::{
+        Coroutine,
+        CoroutineState,
+    },
+    crate::{
+        generator::GeneratorExt,
+        generator_fn::CallBoxed,
+        mk_gen,
+        native::NativeCoroutine,
+        stack_pinned,
+    },
+    super::{Box, Generator, GeneratorState, Pin, Vec, generator},
+};
+
+#[test]
+fn generator_as_native ()
+{
+    #[generator(yield(u8), resume(u8) as mut x)]
+    fn doubler ()
+      -> &'static str
+    {
+        while x != 0 {
+            x = yield_!(2 * x);
+        }
+        "done"
+    }
+
+    fn drive<C : ?Sized + Coroutine<u8>> (coroutine: Pin<&'_ mut C>)
+      -> Vec<CoroutineState<C::Yield, C::Return>>
+    {
+        let mut coroutine = coroutine;
+        (1 ..= 3).rev()
+            .map(|x| coroutine.as_mut().resume(x - 1))
+            .collect()
+    }
+
+    mk_gen!(let generator = doubler());
+    assert_eq!(drive(generator), [
+        CoroutineState::Yielded(4),
+        CoroutineState::Yielded(2),
+        CoroutineState::Complete("done"),
+    ]);
+
+    let mut generator: Pin<Box<dyn Generator<u8, Yield = u8, Return = &str>>> =
+        doubler.call_boxed(())
+    ;
+    assert_eq!(
+        drive(generator.as_mut()),
+        drive(doubler.call_boxed(()).as_mut()),
+    );
+}
+
+#[test]
+fn native_as_generator ()
+{
+    let coroutine = NativeCoroutine::new(#[coroutine] |mut x: u8| {
+        while x != 0 {
+            x = yield 2 * x;
+        }
+        "done"
+    });
+    stack_pinned!(mut coroutine);
+    assert_eq!(coroutine.as_mut().resume(1), GeneratorState::Yielded(2));
+    assert_eq!(coroutine.as_mut().resume(0), GeneratorState::Returned("done"));
+
+    let countdown = NativeCoroutine::new(#[coroutine] static || {
+        let n = 3;
+        let n = &n;
+        for i in (0 .. *n).rev() {
+            yield i;
+        }
+    });
+    stack_pinned!(mut countdown);
+    assert_eq!(countdown.gen_into_iter().collect::<Vec<_>>(), [2, 1, 0]);
+}
+
+#[test]
+fn state_conversions ()
+{
+    let yielded = GeneratorState::<u8, ()>::Yielded(42);
+    assert_eq!(CoroutineState::from(yielded), CoroutineState::Yielded(42));
+    assert_eq!(GeneratorState::from(CoroutineState::from(yielded)), yielded);
+    assert_eq!(
+        GeneratorState::<u8, _>::from(CoroutineState::Complete("done")),
+        GeneratorState::Returned("done"),
+    );
+}
+
+/// A native body wrapped within another future is no longer resumed
+/// directly, but polled as any other one.
+#[cfg(feature = "native-coroutines")]
+#[test]
+fn wrapped_native_body ()
+{
+    use {
+        ::core::{future::Future, task::{Context, Poll}},
+        crate::generator_fn::GeneratorFn,
+    };
+
+    #[generator(yield(u8))]
+    fn countdown (from: u8)
+      -> &'static str
+    {
+        for x in (1 ..= from).rev() {
+            yield_!(x);
+        }
+        "liftoff"
+    }
+
+    /// (The padding shifts the body away from the start of the wrapper.)
+    struct Wrapper<F> { _padding: [u64; 3], body: F }
+
+    impl<F : Future> Future for Wrapper<F> {
+        type Output = F::Output;
+
+        fn poll (self: Pin<&'_ mut Self>, cx: &'_ mut Context<'_>)
+          -> Poll<F::Output>
+        {
+            unsafe { self.map_unchecked_mut(|it| &mut it.body) }.poll(cx)
+        }
+    }
+
+    let generator = GeneratorFn::empty();
+    stack_pinned!(mut generator);
+    generator.as_mut().init(
+        |slot, (from,)| Wrapper {
+            _padding: [0; 3],
+            body: countdown(slot, (from,)),
+        },
+        (3,),
+    );
+    assert_eq!(generator.as_mut().resume(()), GeneratorState::Yielded(3));
+    assert_eq!(generator.as_mut().resume(()), GeneratorState::Yielded(2));
+    assert_eq!(generator.as_mut().resume(()), GeneratorState::Yielded(1));
+    assert_eq!(
+        generator.as_mut().resume(()),
+        GeneratorState::Returned("liftoff"),
+    );
+}
@@ -1,5 +1,6 @@
 use_prelude!();
 
+#[allow(clippy::multiple_bound_locations)]
 pub(in crate)
 fn poll_fn<'F, F : 'F, T> (f: F)
   -> impl 'F + Unpin + Future<Output = T>
@@ -28,6 +29,7 @@ where
     {
         type Output = T;
 
+        #[allow(clippy::needless_borrow)]
         fn poll (
             mut self: Pin<&'_ mut PollFn<F>>,
             cx: &'_ mut Context<'_>,
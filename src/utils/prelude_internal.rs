@@ -4,7 +4,10 @@ use {
         core::{
             cell::Cell,
             future::Future,
-            marker::PhantomPinned,
+            marker::{
+                PhantomData,
+                PhantomPinned,
+            },
             ops::Not,
             pin::Pin,
             task::{
@@ -14,6 +17,9 @@ use {
         },
     },
     crate::{
+        async_generator::{
+            AsyncGenerator,
+        },
         generator::{
             Generator,
             GeneratorState,
@@ -1,6 +1,9 @@
 #![cfg_attr(feature = "nightly",
     feature(bench_black_box),
 )]
+#![cfg_attr(feature = "native-coroutines",
+    feature(coroutines),
+)]
 
 const N: u64 = 1_u64 << 16;
 const OUTPUT: u64 = 2147516416;
@@ -79,28 +82,31 @@ mod auto_heap_state {
 
 
         loop {
-            match last_suspended_computation.resume_unpin(computation_result) {
-                // We reached `return`: completion of the current computation.
-                | GeneratorState::Returned(computation_result_) => {
-                    match suspended_computations.pop() {
-                        // If it was the outer-most computation, we've finished.
-                        | None => return computation_result_,
-                        // Otherwise, feed the current result to the outer
-                        // computation that had previously yield-requested the
-                        // current computation.
-                        | Some(suspended_computation) => {
-                            last_suspended_computation = suspended_computation;
-                            computation_result = computation_result_;
-                        },
-                    }
-                },
+            let state =
+                last_suspended_computation.resume_unpin(computation_result)
+            ;
+            computation_result = match state.into_result() {
                 // We need to "compute-recurse" ourselves with this new `arg`
-                | GeneratorState::Yielded(arg) => {
+                | Ok(arg) => {
                     suspended_computations.push(last_suspended_computation);
                     last_suspended_computation = start_computing(arg);
-                    computation_result = R::default();
+                    R::default()
                 },
-            }
+                // We reached `return`: completion of the current computation.
+                | Err(computation_result) => {
+                    // If it was the outer-most computation, we've finished.
+                    // Otherwise, feed the current result to the outer
+                    // computation that had previously yield-requested the
+                    // current computation.
+                    last_suspended_computation =
+                        match suspended_computations.pop() {
+                            | None => return computation_result,
+                            | Some(it) => it,
+                        }
+                    ;
+                    computation_result
+                },
+            };
         }
     }
 